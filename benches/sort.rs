@@ -0,0 +1,97 @@
+use chunked_vec::{ChunkedVec, ChunkedVecSized};
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+
+const LEN: usize = 100_000;
+
+/// Deterministic xorshift data so every run sorts the same input.
+fn random(len: usize) -> Vec<u64> {
+    let mut seed = 0x2545_f491_4f6c_dd1d_u64;
+    (0..len)
+        .map(|_| {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed
+        })
+        .collect()
+}
+
+fn inputs() -> Vec<(&'static str, Vec<u64>)> {
+    let random = random(LEN);
+    let few_distinct = random.iter().map(|x| x % 16).collect();
+    let mut sorted = random.clone();
+    sorted.sort_unstable();
+    let reversed = sorted.iter().rev().copied().collect();
+    vec![
+        ("random", random),
+        ("few_distinct", few_distinct),
+        ("sorted", sorted),
+        ("reversed", reversed),
+        ("all_equal", vec![7; LEN]),
+    ]
+}
+
+fn chunked<const N: usize>(data: &[u64]) -> ChunkedVec<u64, N> {
+    let mut vec = ChunkedVecSized::with_capacity(data.len());
+    vec.extend(data.iter().copied());
+    vec
+}
+
+fn sort_unstable(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sort_unstable");
+    for (name, data) in inputs() {
+        group.bench_with_input(BenchmarkId::new("Vec", name), &data, |b, data| {
+            b.iter_batched_ref(
+                || data.clone(),
+                |v| v.sort_unstable(),
+                BatchSize::LargeInput,
+            )
+        });
+        group.bench_with_input(
+            BenchmarkId::new("ChunkedVec<_, 64>", name),
+            &data,
+            |b, data| {
+                b.iter_batched_ref(
+                    || chunked::<64>(data),
+                    |v| v.sort_unstable(),
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("ChunkedVec<_, 4096>", name),
+            &data,
+            |b, data| {
+                b.iter_batched_ref(
+                    || chunked::<4096>(data),
+                    |v| v.sort_unstable(),
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+    }
+    group.finish();
+}
+
+fn select_nth_unstable(c: &mut Criterion) {
+    let data = random(LEN);
+    let mut group = c.benchmark_group("select_nth_unstable");
+    group.bench_function("Vec", |b| {
+        b.iter_batched_ref(
+            || data.clone(),
+            |v| *black_box(v.select_nth_unstable(LEN / 2).1),
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("ChunkedVec<_, 64>", |b| {
+        b.iter_batched_ref(
+            || chunked::<64>(&data),
+            |v| *black_box(v.select_nth_unstable(LEN / 2)),
+            BatchSize::LargeInput,
+        )
+    });
+    group.finish();
+}
+
+criterion_group!(benches, sort_unstable, select_nth_unstable);
+criterion_main!(benches);
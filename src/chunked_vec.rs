@@ -10,14 +10,54 @@ use std::mem::MaybeUninit;
 /// # Type Parameters
 /// - `T`: The type of elements to store. Can be any type that satisfies the required trait bounds.
 /// - `N`: The size of each chunk (default: 64). This constant determines how many elements
-///        are stored in each internal chunk. Larger chunks may improve cache locality but
-///        increase memory overhead for partially filled chunks.
+///   are stored in each internal chunk. Larger chunks may improve cache locality but
+///   increase memory overhead for partially filled chunks.
 ///
 /// # Internal Structure
 /// - Elements are stored in a series of fixed-size chunks, each containing exactly `N` elements
 /// - The chunks are managed by a `Vec<Chunk<T, N>>`, where each `Chunk` is a boxed array
 /// - The total number of elements is tracked separately from the chunk storage
 ///
+/// # Stable element addresses
+/// Each chunk is its own heap allocation, and growing the vector only
+/// reallocates the table of chunk pointers, never the chunks. As a result,
+/// an element stays at the same address for as long as it is in the vector,
+/// unless it is moved by an operation that reorders or removes elements.
+///
+/// The following never move existing elements: `push`, `push_get`,
+/// `push_pinned`, `try_push`, `extend`, `try_extend`, `extend_from_slice`,
+/// `append` and `append_vec` (for the elements of `self`), `resize`
+/// (growing), `reserve`, `reserve_exact`, `try_reserve` and
+/// `reserve_from_pool`. `pop`, `truncate`, `clear`, `shrink_to`,
+/// `shrink_to_fit`, `shrink_into_pool` and `split_off` (for the elements
+/// that stay) keep the remaining elements in place.
+///
+/// Operations that shift, reorder or take out elements move them:
+/// `insert`, `remove`, `swap_remove`, `drain`, `retain`, `dedup`, the
+/// `sort*` and `select_nth_unstable*` families, `swap`, `reverse` and
+/// `rotate_*`, as well as moving the elements out by value.
+///
+/// The same property lets the vector hold pinned values, see
+/// [`push_pinned`](ChunkedVec::push_pinned).
+///
+/// # Thread safety
+/// `ChunkedVec<T, N>` has the same auto traits as `Vec<T>`: it is `Send` if
+/// `T: Send` and `Sync` if `T: Sync`. These bounds are stated by explicit
+/// impls, so they do not change if the internal representation does. The
+/// borrowing iterators and slices follow the matching slice types:
+///
+/// | Type | `Send` if | `Sync` if |
+/// |---|---|---|
+/// | `ChunkedVec`, `IntoIter` | `T: Send` | `T: Sync` |
+/// | `Iter`, `Chunks`, `ChunkedSlice` | `T: Sync` | `T: Sync` |
+/// | `IterMut`, `ChunksMut`, `Drain`, `ChunkedSliceMut` | `T: Send` | `T: Sync` |
+///
+/// Likewise, `ChunkedVec<T, N>` is `UnwindSafe` and `RefUnwindSafe` exactly
+/// when `T` is. Methods that run caller code while elements are in flight
+/// (comparators, `clone`, destructors, iterators) leave the vector valid if
+/// that code panics, so it can be used after `catch_unwind`, though its
+/// contents may be unspecified.
+///
 /// # Examples
 /// ```
 /// use chunked_vec::ChunkedVec;
@@ -34,12 +74,18 @@ use std::mem::MaybeUninit;
 /// assert_eq!(vec[1], 2);
 /// assert_eq!(vec.len(), 2);
 /// ```
-#[derive(Debug)]
 pub struct ChunkedVec<T, const N: usize = { crate::DEFAULT_CHUNK_SIZE }> {
     pub(crate) data: Vec<Chunk<T, N>>,
     pub(crate) len: usize,
 }
 
+// Safety: the vector owns its elements, like `Vec<T>`; the chunk table and
+// the chunks are plain owned allocations.
+unsafe impl<T: Send, const N: usize> Send for ChunkedVec<T, N> {}
+
+// Safety: a shared vector only hands out shared references to its elements.
+unsafe impl<T: Sync, const N: usize> Sync for ChunkedVec<T, N> {}
+
 /// A marker type used for compile-time chunk size validation.
 ///
 /// This type is used internally to ensure that chunk sizes are valid at compile time.
@@ -0,0 +1,501 @@
+use std::array::from_fn;
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+use std::ptr;
+
+use crate::{Chunk, ChunkedVec};
+
+use sync::{Access, AtomicBool, AtomicPtr, AtomicUsize, Ordering};
+
+/// Atomics used by [`ConcurrentChunkedVec`], swapped for loom's when the
+/// crate is built with `--cfg loom`.
+#[cfg(not(loom))]
+mod sync {
+    pub(super) use std::sync::atomic::{AtomicBool, AtomicPtr, AtomicUsize, Ordering};
+
+    /// Records accesses to an element slot. Only does something under loom.
+    pub(super) struct Access;
+
+    impl Access {
+        pub(super) fn new() -> Self {
+            Access
+        }
+
+        pub(super) fn write(&self) {}
+
+        pub(super) fn read(&self) {}
+    }
+}
+
+#[cfg(loom)]
+mod sync {
+    pub(super) use loom::sync::atomic::{AtomicBool, AtomicPtr, AtomicUsize, Ordering};
+
+    /// Records accesses to an element slot, so that loom reports reads that
+    /// do not happen after the write.
+    pub(super) struct Access(loom::cell::UnsafeCell<()>);
+
+    impl Access {
+        pub(super) fn new() -> Self {
+            Access(loom::cell::UnsafeCell::new(()))
+        }
+
+        pub(super) fn write(&self) {
+            self.0.with_mut(|_| ());
+        }
+
+        pub(super) fn read(&self) {
+            self.0.with(|_| ());
+        }
+    }
+}
+
+/// Number of segments in the chunk table. Segment `s` holds `2^s` chunk
+/// pointers, which is enough for any index that fits in a `usize`.
+const SEGMENTS: usize = usize::BITS as usize;
+
+/// A chunk together with the flags that publish its elements.
+struct Block<T, const N: usize> {
+    /// The chunk, taken out of its `Box` so writers can reach it through a
+    /// shared reference.
+    values: *mut [MaybeUninit<T>; N],
+    /// `ready[i]` is set once slot `i` has been written.
+    ready: [AtomicBool; N],
+    access: [Access; N],
+}
+
+impl<T, const N: usize> Block<T, N> {
+    fn new() -> Self {
+        Block {
+            values: Box::into_raw(ChunkedVec::<T, N>::create_empty_chunk()),
+            ready: from_fn(|_| AtomicBool::new(false)),
+            access: from_fn(|_| Access::new()),
+        }
+    }
+}
+
+/// An append-only vector that many threads can push to at the same time.
+///
+/// Each [`push`] claims a slot with a single atomic increment and writes the
+/// element in place. The chunk holding the slot is allocated by whichever
+/// thread needs it first and installed with a compare-and-swap; since chunks
+/// never move, no thread ever waits for another or copies elements. Once an
+/// element is published, [`get`] reads it without locking or retrying.
+///
+/// Chunks are found through a table of segments, where segment `s` holds
+/// `2^s` chunk pointers. Segments are allocated on demand like chunks, so the
+/// table never has to be resized either.
+///
+/// When the writers are done, [`into_chunked_vec`] turns the vector into a
+/// plain [`ChunkedVec`] without moving any element.
+///
+/// [`push`]: ConcurrentChunkedVec::push
+/// [`get`]: ConcurrentChunkedVec::get
+/// [`into_chunked_vec`]: ConcurrentChunkedVec::into_chunked_vec
+///
+/// # Examples
+/// ```
+/// use std::thread;
+/// use chunked_vec::{ChunkedVec, ConcurrentChunkedVec};
+///
+/// let results: ConcurrentChunkedVec<u32, 16> = ConcurrentChunkedVec::new();
+/// thread::scope(|s| {
+///     for worker in 0..4 {
+///         let results = &results;
+///         s.spawn(move || {
+///             for i in 0..100 {
+///                 let index = results.push(worker * 100 + i);
+///                 assert_eq!(results.get(index), Some(&(worker * 100 + i)));
+///             }
+///         });
+///     }
+/// });
+///
+/// let mut results: ChunkedVec<u32, 16> = results.into_chunked_vec();
+/// results.sort_unstable();
+/// assert!(results.iter().copied().eq(0..400));
+/// ```
+pub struct ConcurrentChunkedVec<T, const N: usize = { crate::DEFAULT_CHUNK_SIZE }> {
+    segments: [AtomicPtr<AtomicPtr<Block<T, N>>>; SEGMENTS],
+    /// Number of slots claimed by `push`.
+    claimed: AtomicUsize,
+    _marker: PhantomData<*mut T>,
+}
+
+// Safety: the vector owns its elements, so sending it sends them.
+unsafe impl<T: Send, const N: usize> Send for ConcurrentChunkedVec<T, N> {}
+
+// Safety: a shared vector moves elements in from other threads (`push`) and
+// hands out shared references to them (`get`).
+unsafe impl<T: Send + Sync, const N: usize> Sync for ConcurrentChunkedVec<T, N> {}
+
+impl<T, const N: usize> ConcurrentChunkedVec<T, N> {
+    /// Creates an empty vector. No memory is allocated until the first push.
+    pub fn new() -> Self {
+        ConcurrentChunkedVec {
+            segments: from_fn(|_| AtomicPtr::new(ptr::null_mut())),
+            claimed: AtomicUsize::new(0),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the number of slots claimed so far.
+    ///
+    /// While pushes are in flight, some of these slots may not be published
+    /// yet, so [`get`](ConcurrentChunkedVec::get) can return `None` for an
+    /// index below `len`.
+    pub fn len(&self) -> usize {
+        self.claimed.load(Ordering::Acquire)
+    }
+
+    /// Returns `true` if no slot has been claimed yet.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Appends an element and returns its index.
+    ///
+    /// Indices are handed out in the order slots are claimed, so each thread
+    /// sees its own pushes at increasing indices.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ConcurrentChunkedVec;
+    ///
+    /// let vec: ConcurrentChunkedVec<&str, 4> = ConcurrentChunkedVec::new();
+    /// assert_eq!(vec.push("a"), 0);
+    /// assert_eq!(vec.push("b"), 1);
+    /// assert_eq!(vec.get(1), Some(&"b"));
+    /// ```
+    pub fn push(&self, value: T) -> usize {
+        let index = self.claimed.fetch_add(1, Ordering::Relaxed);
+        let (chunk_idx, offset) = (index / N, index % N);
+        let block = self.get_or_allocate_block(chunk_idx);
+
+        // Safety: the slot was claimed by this call alone, and the chunk is
+        // only freed when the vector is dropped or converted.
+        unsafe {
+            block.access[offset].write();
+            (*block.values)[offset].write(value);
+        }
+        block.ready[offset].store(true, Ordering::Release);
+        index
+    }
+
+    /// Returns a reference to the element at `index`, or `None` if it has not
+    /// been published yet.
+    ///
+    /// An element is published as soon as the `push` that stored it returns.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        let block = self.block(index / N)?;
+        let offset = index % N;
+        if !block.ready[offset].load(Ordering::Acquire) {
+            return None;
+        }
+
+        block.access[offset].read();
+        // Safety: the acquire load above saw the flag set after the write.
+        Some(unsafe { (*block.values)[offset].assume_init_ref() })
+    }
+
+    /// Converts the vector into a [`ChunkedVec`], keeping the elements in
+    /// place.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkedVec, ConcurrentChunkedVec};
+    ///
+    /// let vec: ConcurrentChunkedVec<i32, 2> = ConcurrentChunkedVec::new();
+    /// for i in 0..5 {
+    ///     vec.push(i);
+    /// }
+    /// let vec: ChunkedVec<i32, 2> = vec.into_chunked_vec();
+    /// assert_eq!(vec, [0, 1, 2, 3, 4]);
+    /// ```
+    pub fn into_chunked_vec(mut self) -> ChunkedVec<T, N> {
+        self.take_chunks()
+    }
+
+    /// Moves every chunk into a `ChunkedVec`, leaving only the segments.
+    fn take_chunks(&mut self) -> ChunkedVec<T, N> {
+        // Holding `&mut self` means every push has returned. A push cannot
+        // fail between claiming its slot and publishing it (allocation
+        // failure aborts), so every claimed slot is written and every chunk
+        // below `len` exists.
+        let len = self.claimed.swap(0, Ordering::Acquire);
+        let chunk_count = len.div_ceil(N);
+
+        let mut data: Vec<Chunk<T, N>> = Vec::with_capacity(chunk_count);
+        for chunk_idx in 0..chunk_count {
+            let (segment, pos) = Self::locate(chunk_idx);
+            let entries = self.segments[segment].load(Ordering::Acquire);
+            // Safety: the segment and the block exist (see above). Nulling
+            // the block pointer hands the chunk over.
+            unsafe {
+                let entry = &*entries.add(pos);
+                let block = Box::from_raw(entry.swap(ptr::null_mut(), Ordering::Acquire));
+                data.push(Box::from_raw(block.values));
+            }
+        }
+        ChunkedVec { data, len }
+    }
+
+    /// Returns the segment and the position within it of chunk `chunk_idx`.
+    fn locate(chunk_idx: usize) -> (usize, usize) {
+        let n = chunk_idx + 1;
+        let segment = n.ilog2() as usize;
+        (segment, n - (1 << segment))
+    }
+
+    /// Returns the block for `chunk_idx`, or `None` if it has not been
+    /// allocated.
+    fn block(&self, chunk_idx: usize) -> Option<&Block<T, N>> {
+        if chunk_idx == usize::MAX {
+            // Only reachable with `N == 1`, and never pushed to: the counter
+            // would overflow first
+            return None;
+        }
+        let (segment, pos) = Self::locate(chunk_idx);
+        let entries = self.segments[segment].load(Ordering::Acquire);
+        if entries.is_null() {
+            return None;
+        }
+        // Safety: a non-null segment holds `2^segment` entries and lives as
+        // long as `self`; so does a non-null block.
+        unsafe {
+            let block = (*entries.add(pos)).load(Ordering::Acquire);
+            block.as_ref()
+        }
+    }
+
+    /// Returns the block for `chunk_idx`, allocating it and its segment if
+    /// needed.
+    fn get_or_allocate_block(&self, chunk_idx: usize) -> &Block<T, N> {
+        let (segment, pos) = Self::locate(chunk_idx);
+        let entries = get_or_allocate(
+            &self.segments[segment],
+            || allocate_segment(segment),
+            // Safety: the losing segment was never shared.
+            |entries| unsafe { free_segment(entries, segment) },
+        );
+        // Safety: segments live as long as `self`.
+        let entry = unsafe { &*entries.add(pos) };
+        let block = get_or_allocate(
+            entry,
+            || Box::into_raw(Box::new(Block::new())),
+            // Safety: the losing block was never shared.
+            |block| unsafe { free_block(block) },
+        );
+        // Safety: blocks live as long as `self`.
+        unsafe { &*block }
+    }
+}
+
+/// Allocates segment `segment`, with `2^segment` null chunk pointers.
+fn allocate_segment<P>(segment: usize) -> *mut AtomicPtr<P> {
+    let entries: Box<[AtomicPtr<P>]> = (0..1usize << segment)
+        .map(|_| AtomicPtr::new(ptr::null_mut()))
+        .collect();
+    Box::into_raw(entries).cast()
+}
+
+/// Frees a segment allocated by `allocate_segment`, but not its blocks.
+///
+/// # Safety
+/// `entries` must come from `allocate_segment(segment)` and must not be used
+/// afterwards.
+unsafe fn free_segment<P>(entries: *mut AtomicPtr<P>, segment: usize) {
+    drop(Box::from_raw(ptr::slice_from_raw_parts_mut(
+        entries,
+        1 << segment,
+    )));
+}
+
+/// Frees a block and its chunk, without dropping any element.
+///
+/// # Safety
+/// `block` must come from `Box::into_raw` and must not be used afterwards.
+unsafe fn free_block<T, const N: usize>(block: *mut Block<T, N>) {
+    let block = Box::from_raw(block);
+    drop(Box::from_raw(block.values));
+}
+
+/// Returns the pointer stored in `slot`, installing the one built by
+/// `allocate` if the slot is null.
+///
+/// When several threads race, exactly one allocation is installed and the
+/// others are handed to `free`.
+fn get_or_allocate<P>(
+    slot: &AtomicPtr<P>,
+    allocate: impl FnOnce() -> *mut P,
+    free: impl FnOnce(*mut P),
+) -> *mut P {
+    let current = slot.load(Ordering::Acquire);
+    if !current.is_null() {
+        return current;
+    }
+
+    let new = allocate();
+    match slot.compare_exchange(ptr::null_mut(), new, Ordering::AcqRel, Ordering::Acquire) {
+        Ok(_) => new,
+        Err(winner) => {
+            free(new);
+            winner
+        }
+    }
+}
+
+impl<T, const N: usize> Default for ConcurrentChunkedVec<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> fmt::Debug for ConcurrentChunkedVec<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConcurrentChunkedVec")
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T, const N: usize> From<ConcurrentChunkedVec<T, N>> for ChunkedVec<T, N> {
+    fn from(vec: ConcurrentChunkedVec<T, N>) -> Self {
+        vec.into_chunked_vec()
+    }
+}
+
+impl<T, const N: usize> Drop for ConcurrentChunkedVec<T, N> {
+    fn drop(&mut self) {
+        let elements = self.take_chunks();
+        for (segment, entries) in self.segments.iter().enumerate() {
+            let entries = entries.load(Ordering::Acquire);
+            if entries.is_null() {
+                continue;
+            }
+            // Safety: `take_chunks` took every block, so only the segment is
+            // left to free.
+            unsafe { free_segment(entries, segment) };
+        }
+        // Dropped last, so that a panicking element does not leak the table
+        drop(elements);
+    }
+}
+
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use super::ConcurrentChunkedVec;
+    use crate::ChunkedVec;
+    use std::cell::Cell;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use std::rc::Rc;
+    use std::thread;
+
+    #[test]
+    fn test_locate() {
+        type Vec4 = ConcurrentChunkedVec<u8, 4>;
+        let located: Vec<_> = (0..7).map(Vec4::locate).collect();
+        assert_eq!(
+            located,
+            [(0, 0), (1, 0), (1, 1), (2, 0), (2, 1), (2, 2), (2, 3)]
+        );
+        let bits = usize::BITS as usize;
+        assert_eq!(Vec4::locate(usize::MAX / 4), (bits - 2, 0));
+        assert_eq!(Vec4::locate(usize::MAX - 1), (bits - 1, usize::MAX / 2));
+    }
+
+    #[test]
+    fn test_concurrent_push() {
+        let vec: ConcurrentChunkedVec<usize, 8> = ConcurrentChunkedVec::new();
+        thread::scope(|s| {
+            for t in 0..8 {
+                let vec = &vec;
+                s.spawn(move || {
+                    let mut last = None;
+                    for i in 0..1000 {
+                        let index = vec.push(t * 1000 + i);
+                        assert!(last < Some(index));
+                        last = Some(index);
+                        assert_eq!(vec.get(index), Some(&(t * 1000 + i)));
+                    }
+                });
+            }
+        });
+        assert_eq!(vec.len(), 8000);
+
+        let mut vec: ChunkedVec<usize, 8> = vec.into();
+        assert_eq!(vec.allocated_capacity(), 8000);
+        vec.sort_unstable();
+        assert!(vec.iter().copied().eq(0..8000));
+    }
+
+    #[test]
+    fn test_get_unpublished() {
+        let vec: ConcurrentChunkedVec<String, 4> = ConcurrentChunkedVec::default();
+        assert!(vec.is_empty());
+        assert_eq!(vec.get(0), None);
+        assert_eq!(vec.get(usize::MAX), None);
+        let single: ConcurrentChunkedVec<u8, 1> = ConcurrentChunkedVec::new();
+        assert_eq!(single.get(usize::MAX), None);
+
+        vec.push("a".to_string());
+        assert_eq!(vec.get(0).map(String::as_str), Some("a"));
+        assert_eq!(vec.get(1), None);
+        assert_eq!(vec.get(4), None);
+        assert_eq!(format!("{:?}", vec), "ConcurrentChunkedVec { len: 1, .. }");
+    }
+
+    #[test]
+    fn test_into_chunked_vec_keeps_elements_in_place() {
+        let vec: ConcurrentChunkedVec<u32, 3> = ConcurrentChunkedVec::new();
+        let addrs: Vec<*const u32> = (0..10)
+            .map(|i| vec.get(vec.push(i)).unwrap() as *const u32)
+            .collect();
+
+        let mut vec = vec.into_chunked_vec();
+        assert_eq!(vec, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        assert!(vec.iter().map(|elem| elem as *const u32).eq(addrs));
+        vec.push(10);
+        assert_eq!(vec.len(), 11);
+
+        let empty: ChunkedVec<u32, 3> = ConcurrentChunkedVec::new().into_chunked_vec();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_drop_elements() {
+        let value = Rc::new(());
+        let vec: ConcurrentChunkedVec<Rc<()>, 3> = ConcurrentChunkedVec::new();
+        for _ in 0..7 {
+            vec.push(value.clone());
+        }
+        assert_eq!(Rc::strong_count(&value), 8);
+        drop(vec);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn test_drop_continues_after_panic() {
+        struct Bomb<'a>(&'a Cell<usize>, bool);
+
+        impl Drop for Bomb<'_> {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+                if self.1 {
+                    panic!("drop failed");
+                }
+            }
+        }
+
+        let drops = Cell::new(0);
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            let vec: ConcurrentChunkedVec<Bomb, 2> = ConcurrentChunkedVec::new();
+            for i in 0..5 {
+                vec.push(Bomb(&drops, i == 0));
+            }
+        }));
+        assert!(result.is_err());
+        assert_eq!(drops.get(), 5);
+    }
+}
@@ -1,10 +1,15 @@
-use crate::ChunkedVec;
+use std::mem::ManuallyDrop;
+
+use crate::{ChunkedVec, ChunkedVecSized};
 
 /// Implements the `FromIterator` trait for `ChunkedVec`, allowing it to be created from any iterator.
 ///
 /// This implementation provides an efficient way to collect elements from an iterator into a `ChunkedVec`.
-/// It pre-allocates space based on the iterator's size hint when available, which can improve performance
-/// by reducing the number of reallocations.
+/// It allocates chunks upfront for the iterator's lower size bound and fills each chunk in one pass,
+/// exactly like [`Extend`].
+///
+/// The implementation is generic over the chunk size `N`, so the target chunk size is picked by the
+/// annotated type.
 ///
 /// # Examples
 /// ```
@@ -13,58 +18,67 @@ use crate::ChunkedVec;
 /// let vec = vec![1, 2, 3, 4, 5];
 /// let chunked_vec: ChunkedVec<_> = vec.into_iter().collect();
 /// assert_eq!(chunked_vec.len(), 5);
+///
+/// let sized = (0..10).collect::<ChunkedVec<u32, 4>>();
+/// assert_eq!(sized.allocated_capacity(), 12);
 /// ```
-impl<T> FromIterator<T> for ChunkedVec<T> {
+impl<T, const N: usize> FromIterator<T> for ChunkedVec<T, N> {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
-        let iter = iter.into_iter();
-        let (lower, upper) = iter.size_hint();
-        let mut chunked_vec = ChunkedVec::with_capacity(upper.unwrap_or(lower));
-        for item in iter {
-            chunked_vec.push(item);
-        }
+        let mut chunked_vec = ChunkedVecSized::new();
+        chunked_vec.extend(iter);
         chunked_vec
     }
 }
 
-/// Implements conversion from `Vec<T>` to `ChunkedVec<T>`.
+/// Implements conversion from `Vec<T>` to `ChunkedVec<T, N>`.
 ///
-/// This implementation efficiently converts a standard vector into a `ChunkedVec` by
-/// consuming the original vector and reusing its memory allocation when possible.
+/// This implementation consumes the original vector and moves its elements into the chunks
+/// with one `ptr::copy_nonoverlapping` per chunk, without cloning them.
 ///
 /// # Examples
 /// ```
 /// use chunked_vec::ChunkedVec;
 ///
 /// let vec = vec![1, 2, 3];
-/// let chunked_vec = ChunkedVec::from(vec);
+/// let chunked_vec: ChunkedVec<_> = ChunkedVec::from(vec);
 /// assert_eq!(chunked_vec[0], 1);
 /// ```
-impl<T> From<Vec<T>> for ChunkedVec<T> {
-    fn from(vec: Vec<T>) -> Self {
-        Self::from_iter(vec)
+impl<T, const N: usize> From<Vec<T>> for ChunkedVec<T, N> {
+    fn from(mut vec: Vec<T>) -> Self {
+        let mut chunked_vec = ChunkedVecSized::new();
+        chunked_vec.append_vec(&mut vec);
+        chunked_vec
     }
 }
 
-/// Implements conversion from fixed-size arrays to `ChunkedVec<T>`.
+/// Implements conversion from fixed-size arrays to `ChunkedVec<T, N>`.
 ///
 /// This allows creating a `ChunkedVec` from any array of known size `M`.
-/// The conversion preserves the order of elements.
+/// The conversion preserves the order of elements, which are moved into the chunks
+/// with one `ptr::copy_nonoverlapping` per chunk.
 ///
 /// # Examples
 /// ```
 /// use chunked_vec::ChunkedVec;
 ///
 /// let array = [1, 2, 3];
-/// let chunked_vec = ChunkedVec::from(array);
+/// let chunked_vec: ChunkedVec<_> = ChunkedVec::from(array);
 /// assert_eq!(chunked_vec[0], 1);
 /// ```
-impl<T, const M: usize> From<[T; M]> for ChunkedVec<T> {
+impl<T, const N: usize, const M: usize> From<[T; M]> for ChunkedVec<T, N> {
     fn from(array: [T; M]) -> Self {
-        Self::from_iter(array)
+        let array = ManuallyDrop::new(array);
+        let mut chunked_vec: ChunkedVec<T, N> = ChunkedVecSized::new();
+        chunked_vec.allocate_chunks_for(M);
+        // Safety: the chunks hold `M` unused slots, and the elements are
+        // never dropped through the array again.
+        unsafe { chunked_vec.copy_range_in(array.as_ptr(), 0, M) };
+        chunked_vec.len = M;
+        chunked_vec
     }
 }
 
-/// Implements conversion from references to fixed-size arrays to `ChunkedVec<T>`.
+/// Implements conversion from references to fixed-size arrays to `ChunkedVec<T, N>`.
 ///
 /// This allows creating a `ChunkedVec` from a reference to any array of known size `M`.
 /// The conversion preserves the order of elements.
@@ -74,18 +88,19 @@ impl<T, const M: usize> From<[T; M]> for ChunkedVec<T> {
 /// use chunked_vec::ChunkedVec;
 ///
 /// let array = &[1, 2, 3];
-/// let chunked_vec = ChunkedVec::from(array);
+/// let chunked_vec: ChunkedVec<_> = ChunkedVec::from(array);
 /// assert_eq!(chunked_vec[0], 1);
 /// ```
-impl<T: Clone, const M: usize> From<&[T; M]> for ChunkedVec<T> {
+impl<T: Clone, const N: usize, const M: usize> From<&[T; M]> for ChunkedVec<T, N> {
     fn from(array: &[T; M]) -> Self {
-        Self::from_iter(array.iter().cloned())
+        Self::from(&array[..])
     }
 }
 
-/// Implements conversion from slices to `ChunkedVec<T>`.
+/// Implements conversion from slices to `ChunkedVec<T, N>`.
 ///
-/// This implementation creates a new `ChunkedVec` by cloning elements from the slice.
+/// This implementation creates a new `ChunkedVec` by cloning elements from the slice with
+/// [`ChunkedVec::extend_from_slice`], so full chunks are cloned in bulk.
 /// The original slice remains unchanged and available for further use.
 ///
 /// # Examples
@@ -93,12 +108,14 @@ impl<T: Clone, const M: usize> From<&[T; M]> for ChunkedVec<T> {
 /// use chunked_vec::ChunkedVec;
 ///
 /// let slice: &[i32] = &[1, 2, 3];
-/// let chunked_vec = ChunkedVec::from(slice);
+/// let chunked_vec: ChunkedVec<_> = ChunkedVec::from(slice);
 /// assert_eq!(chunked_vec[0], 1);
 /// ```
-impl<T: Clone> From<&[T]> for ChunkedVec<T> {
+impl<T: Clone, const N: usize> From<&[T]> for ChunkedVec<T, N> {
     fn from(slice: &[T]) -> Self {
-        Self::from_iter(slice.iter().cloned())
+        let mut chunked_vec = ChunkedVecSized::new();
+        chunked_vec.extend_from_slice(slice);
+        chunked_vec
     }
 }
 
@@ -120,7 +137,7 @@ mod tests {
         let chunked_vec: ChunkedVec<_> = vec.into();
         assert_eq!(chunked_vec.len(), 5);
 
-        let chunked_vec = ChunkedVec::from(vec![2, 3, 1]);
+        let chunked_vec: ChunkedVec<_> = ChunkedVec::from(vec![2, 3, 1]);
         assert_eq!(chunked_vec[0], 2);
         assert_eq!(chunked_vec[1], 3);
         assert_eq!(chunked_vec[2], 1);
@@ -128,7 +145,7 @@ mod tests {
 
     #[test]
     fn test_from_array() {
-        let chunked_vec = ChunkedVec::from([2, 3, 1]);
+        let chunked_vec: ChunkedVec<_> = ChunkedVec::from([2, 3, 1]);
         assert_eq!(chunked_vec[0], 2);
         assert_eq!(chunked_vec[1], 3);
         assert_eq!(chunked_vec[2], 1);
@@ -137,7 +154,7 @@ mod tests {
     #[test]
     fn test_from_array_ref() {
         let array = [2, 3, 1];
-        let chunked_vec = ChunkedVec::from(&array);
+        let chunked_vec: ChunkedVec<_> = ChunkedVec::from(&array);
         assert_eq!(chunked_vec[0], 2);
         assert_eq!(chunked_vec[1], 3);
         assert_eq!(chunked_vec[2], 1);
@@ -149,9 +166,72 @@ mod tests {
     #[test]
     fn test_from_slice() {
         let slice: &[i32] = &[2, 3, 1];
-        let chunked_vec = ChunkedVec::from(slice);
+        let chunked_vec: ChunkedVec<_> = ChunkedVec::from(slice);
         assert_eq!(chunked_vec[0], 2);
         assert_eq!(chunked_vec[1], 3);
         assert_eq!(chunked_vec[2], 1);
     }
+
+    fn check_sizes<const N: usize>() {
+        let data: Vec<i32> = (0..20).collect();
+
+        let collected: ChunkedVec<i32, N> = data.iter().copied().collect();
+        let from_vec: ChunkedVec<i32, N> = ChunkedVec::from(data.clone());
+        let from_slice: ChunkedVec<i32, N> = ChunkedVec::from(data.as_slice());
+        let from_array: ChunkedVec<i32, N> = ChunkedVec::from([0, 1, 2, 3, 4]);
+        let from_array_ref: ChunkedVec<i32, N> = ChunkedVec::from(&[0, 1, 2, 3, 4]);
+
+        for vec in [&collected, &from_vec, &from_slice] {
+            assert_eq!(vec.len(), 20);
+            assert_eq!(vec.allocated_capacity(), 20usize.div_ceil(N) * N);
+            assert!(vec.iter().eq(data.iter()));
+        }
+        for vec in [&from_array, &from_array_ref] {
+            assert_eq!(vec.len(), 5);
+            assert!(vec.iter().eq(data[..5].iter()));
+        }
+    }
+
+    #[test]
+    fn test_conversions_generic_chunk_size() {
+        check_sizes::<1>();
+        check_sizes::<3>();
+        check_sizes::<4>();
+        check_sizes::<20>();
+        check_sizes::<256>();
+    }
+
+    #[test]
+    fn test_collect_turbofish_chunk_size() {
+        let vec = (0..1000u32).collect::<ChunkedVec<u32, 256>>();
+        assert_eq!(vec.len(), 1000);
+        assert_eq!(vec.allocated_capacity(), 1024);
+        assert_eq!(vec[999], 999);
+    }
+
+    #[test]
+    fn test_from_vec_moves_elements() {
+        use std::rc::Rc;
+
+        let value = Rc::new(());
+        let source: Vec<Rc<()>> = (0..10).map(|_| value.clone()).collect();
+        let vec: ChunkedVec<Rc<()>, 4> = ChunkedVec::from(source);
+        assert_eq!(vec.len(), 10);
+        assert_eq!(vec.allocated_capacity(), 12);
+        // Nothing was cloned or dropped by the conversion
+        assert_eq!(Rc::strong_count(&value), 11);
+
+        drop(vec);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn test_from_array_moves_elements() {
+        let vec: ChunkedVec<String, 2> = ChunkedVec::from(["a", "b", "c"].map(String::from));
+        assert_eq!(vec, ["a", "b", "c"]);
+        assert_eq!(vec.allocated_capacity(), 4);
+
+        let empty: ChunkedVec<String, 2> = ChunkedVec::from([]);
+        assert_eq!(empty.allocated_capacity(), 0);
+    }
 }
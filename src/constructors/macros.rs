@@ -28,6 +28,23 @@
 /// assert_eq!(vec.len(), 0);
 /// ```
 ///
+/// # Choosing the chunk size
+/// Every form above uses the default chunk size. Prefix the arguments with
+/// `chunk_size = N;` to build a `ChunkedVec<T, N>` instead; `N` must be a
+/// constant expression.
+/// ```
+/// # use chunked_vec::chunked_vec;
+/// use chunked_vec::ChunkedVec;
+/// let vec = chunked_vec![chunk_size = 4; 1, 2, 3, 4, 5];
+/// assert_eq!(vec.allocated_capacity(), 8);
+///
+/// let vec = chunked_vec![chunk_size = 4; 0; 6];
+/// assert_eq!(vec.len(), 6);
+///
+/// let vec: ChunkedVec<i32, 4> = chunked_vec![chunk_size = 4];
+/// assert!(vec.is_empty());
+/// ```
+///
 /// # Notes
 /// - Like the standard `vec!` macro, this macro works with any type that implements `Clone`
 /// - When using `chunked_vec![elem; n]` syntax, the element will be cloned n times
@@ -35,6 +52,19 @@
 ///
 #[macro_export]
 macro_rules! chunked_vec {
+    (chunk_size = $size:expr $(;)?) => {
+        $crate::ChunkedVecSized::<_, { $size }>::new()
+    };
+    (chunk_size = $size:expr; $elem:expr; $n:expr) => {{
+        let mut vec = $crate::ChunkedVecSized::<_, { $size }>::with_capacity($n);
+        vec.extend(::std::iter::repeat($elem).take($n));
+        vec
+    }};
+    (chunk_size = $size:expr; $($x:expr),+ $(,)?) => {{
+        let mut vec = $crate::ChunkedVecSized::<_, { $size }>::new();
+        $(vec.push($x);)+
+        vec
+    }};
     () => {
         $crate::ChunkedVec::new()
     };
@@ -86,4 +116,23 @@ mod tests {
         assert_eq!(v[1], 2);
         assert_eq!(v[2], 3);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_chunked_vec_with_chunk_size() {
+        let empty: ChunkedVec<i32, 4> = chunked_vec![chunk_size = 4];
+        assert!(empty.is_empty());
+
+        let v = chunked_vec![chunk_size = 2; 1, 2, 3,];
+        let _: &ChunkedVec<i32, 2> = &v;
+        assert_eq!(v.len(), 3);
+        assert_eq!(v.allocated_capacity(), 4);
+        assert_eq!(v[2], 3);
+
+        const SIZE: usize = 3;
+        let v = chunked_vec![chunk_size = SIZE; 7; 5];
+        let _: &ChunkedVec<i32, 3> = &v;
+        assert_eq!(v.len(), 5);
+        assert_eq!(v.allocated_capacity(), 6);
+        assert!(v.iter().all(|&x| x == 7));
+    }
+}
@@ -0,0 +1,134 @@
+use std::fmt;
+
+use crate::ChunkedVec;
+
+/// Formats the elements as a list, exactly like `Vec<T>` does.
+///
+/// The chunk structure is not shown; use [`ChunkedVec::debug_layout`] for that.
+///
+/// # Examples
+/// ```
+/// use chunked_vec::{ChunkedVec, ChunkedVecSized};
+/// let mut vec: ChunkedVec<i32, 2> = ChunkedVecSized::new();
+/// vec.extend([1, 2, 3]);
+/// assert_eq!(format!("{:?}", vec), "[1, 2, 3]");
+/// assert_eq!(format!("{:#?}", vec), format!("{:#?}", vec![1, 2, 3]));
+/// ```
+impl<T: fmt::Debug, const N: usize> fmt::Debug for ChunkedVec<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// A diagnostic view of how a ChunkedVec uses its memory.
+///
+/// This struct is created by the [`debug_layout`] method on [`ChunkedVec`].
+/// See its documentation for more.
+///
+/// [`debug_layout`]: ChunkedVec::debug_layout
+pub struct DebugLayout<'a, T, const N: usize> {
+    vec: &'a ChunkedVec<T, N>,
+}
+
+impl<T, const N: usize> ChunkedVec<T, N> {
+    /// Returns a value whose `Debug` output describes the storage layout
+    /// instead of the elements.
+    ///
+    /// The output lists the length, the chunk size, the number of allocated
+    /// chunks, the allocated and reserved capacity, the number of spare slots
+    /// in allocated chunks and the fill of every allocated chunk. It does not
+    /// require `T: Debug`.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkedVec, ChunkedVecSized};
+    /// let mut vec: ChunkedVec<i32, 4> = ChunkedVecSized::with_capacity(12);
+    /// vec.extend(0..10);
+    /// vec.truncate(5);
+    /// assert_eq!(
+    ///     format!("{:?}", vec.debug_layout()),
+    ///     "ChunkedVec { len: 5, chunk_size: 4, chunks: 3, allocated_capacity: 12, \
+    ///      capacity: 12, spare: 7, fill: [4, 1, 0] }"
+    /// );
+    /// ```
+    pub fn debug_layout(&self) -> DebugLayout<'_, T, N> {
+        DebugLayout { vec: self }
+    }
+}
+
+impl<T, const N: usize> fmt::Debug for DebugLayout<'_, T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        /// Lists the number of initialized elements in each allocated chunk.
+        struct Fill<const N: usize> {
+            len: usize,
+            chunks: usize,
+        }
+
+        impl<const N: usize> fmt::Debug for Fill<N> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_list()
+                    .entries((0..self.chunks).map(|i| self.len.saturating_sub(i * N).min(N)))
+                    .finish()
+            }
+        }
+
+        let vec = self.vec;
+        f.debug_struct("ChunkedVec")
+            .field("len", &vec.len)
+            .field("chunk_size", &N)
+            .field("chunks", &vec.data.len())
+            .field("allocated_capacity", &vec.allocated_capacity())
+            .field("capacity", &vec.capacity())
+            .field("spare", &(vec.allocated_capacity() - vec.len))
+            .field(
+                "fill",
+                &Fill::<N> {
+                    len: vec.len,
+                    chunks: vec.data.len(),
+                },
+            )
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ChunkedVec, ChunkedVecSized};
+
+    #[test]
+    fn test_debug_matches_vec() {
+        for len in 0..10 {
+            let vec: ChunkedVec<String, 3> = (0..len).map(|i| i.to_string()).collect();
+            let expected: Vec<String> = (0..len).map(|i| i.to_string()).collect();
+            assert_eq!(format!("{:?}", vec), format!("{:?}", expected));
+            assert_eq!(format!("{:#?}", vec), format!("{:#?}", expected));
+        }
+    }
+
+    #[test]
+    fn test_debug_layout() {
+        // No Debug bound on the element type
+        struct Opaque;
+
+        let mut vec: ChunkedVec<Opaque, 4> = ChunkedVecSized::with_capacity(16);
+        assert_eq!(
+            format!("{:?}", vec.debug_layout()),
+            "ChunkedVec { len: 0, chunk_size: 4, chunks: 0, allocated_capacity: 0, \
+             capacity: 16, spare: 0, fill: [] }"
+        );
+
+        for _ in 0..8 {
+            vec.push(Opaque);
+        }
+        assert_eq!(
+            format!("{:?}", vec.debug_layout()),
+            "ChunkedVec { len: 8, chunk_size: 4, chunks: 2, allocated_capacity: 8, \
+             capacity: 16, spare: 0, fill: [4, 4] }"
+        );
+
+        vec.clear();
+        let pretty = format!("{:#?}", vec.debug_layout());
+        assert!(pretty.contains("    spare: 8,\n"));
+        assert!(pretty.contains("    fill: [\n        0,\n        0,\n    ],\n"));
+    }
+}
@@ -1,28 +1,11 @@
 use crate::ChunkedVec;
-use std::ptr;
 
 impl<T, const N: usize> Drop for ChunkedVec<T, N> {
     fn drop(&mut self) {
-        if !std::mem::needs_drop::<T>() {
-            return;
-        }
-
-        let mut remaining = self.len;
-        for chunk in std::mem::take(&mut self.data).iter_mut() {
-            let to_drop = remaining.min(N);
-            if to_drop == 0 {
-                break;
-            }
-
-            let chunk_ptr = chunk.as_mut_ptr();
-            unsafe {
-                ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
-                    chunk_ptr.cast::<T>(),
-                    to_drop,
-                ));
-            }
-            remaining -= to_drop;
-        }
+        // Safety: every slot below `len` is initialized, and the vector is
+        // never used again. The chunks themselves are freed afterwards, when
+        // `data` is dropped, even if an element's destructor panics.
+        unsafe { self.drop_range(0, self.len) };
     }
 }
 
@@ -60,7 +43,7 @@ mod memory_safety_tests {
         {
             let mut vec: ChunkedVec<Option<Droper>, 2> = ChunkedVecSized::new();
             // extended None should not trigger drop
-            vec.extend(std::iter::repeat(None).take(4));
+            vec.extend(std::iter::repeat_n(None, 4));
 
             // add actual values
             vec.push(Some(Droper::new(1)));
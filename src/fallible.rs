@@ -0,0 +1,222 @@
+use std::collections::TryReserveError;
+use std::error::Error;
+use std::fmt;
+
+use crate::ChunkedVec;
+
+/// The error returned when an element could not be stored because memory
+/// could not be allocated.
+///
+/// It hands the element back to the caller, together with the underlying
+/// [`TryReserveError`].
+///
+/// # Examples
+/// ```
+/// use chunked_vec::{ChunkedVec, ChunkedVecSized};
+/// let mut vec: ChunkedVec<String, 4> = ChunkedVecSized::new();
+/// match vec.try_push("hello".to_string()) {
+///     Ok(()) => assert_eq!(vec, ["hello"]),
+///     Err(err) => {
+///         let value: String = err.into_value();
+///         assert_eq!(value, "hello");
+///     }
+/// }
+/// ```
+pub struct TryPushError<T> {
+    value: T,
+    error: TryReserveError,
+}
+
+impl<T> TryPushError<T> {
+    /// Returns the element that could not be stored.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Consumes the error and returns the element that could not be stored.
+    pub fn into_value(self) -> T {
+        self.value
+    }
+
+    /// Returns the allocation error that caused the failure.
+    pub fn error(&self) -> &TryReserveError {
+        &self.error
+    }
+}
+
+impl<T> fmt::Debug for TryPushError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryPushError")
+            .field("error", &self.error)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> fmt::Display for TryPushError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "could not store element: {}", self.error)
+    }
+}
+
+impl<T> Error for TryPushError<T> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Implementation of fallible insertion for ChunkedVec.
+///
+/// These methods never abort on allocation failure. Chunks and chunk table
+/// slots are allocated through [`ChunkedVec::try_reserve`], and when that
+/// fails the element is handed back inside a [`TryPushError`] with the
+/// vector left unchanged.
+impl<T, const N: usize> ChunkedVec<T, N> {
+    /// Appends an element to the back of the vector, or returns it inside an
+    /// error if a new chunk could not be allocated.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkedVec, ChunkedVecSized};
+    /// let mut vec: ChunkedVec<i32, 2> = ChunkedVecSized::new();
+    /// vec.try_push(1).unwrap();
+    /// vec.try_push(2).unwrap();
+    /// vec.try_push(3).unwrap();
+    /// assert_eq!(vec, [1, 2, 3]);
+    /// ```
+    pub fn try_push(&mut self, value: T) -> Result<(), TryPushError<T>> {
+        if let Err(error) = self.try_reserve(1) {
+            return Err(TryPushError { value, error });
+        }
+
+        let (chunk_idx, offset) = self.chunk_and_offset(self.len);
+        // Safety: the reservation above allocated the chunk, and the slot at
+        // `len` is unused.
+        unsafe { self.get_elem_mut_ptr(chunk_idx, offset).write(value) };
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Inserts an element at position `index`, shifting all elements after
+    /// it to the right, or returns it inside an error if a new chunk could
+    /// not be allocated.
+    ///
+    /// # Panics
+    /// Panics if `index > len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkedVec, ChunkedVecSized};
+    /// let mut vec: ChunkedVec<i32, 2> = ChunkedVecSized::new();
+    /// vec.extend([1, 3]);
+    /// vec.try_insert(1, 2).unwrap();
+    /// assert_eq!(vec, [1, 2, 3]);
+    /// ```
+    pub fn try_insert(&mut self, index: usize, element: T) -> Result<(), TryPushError<T>> {
+        let len = self.len;
+        if index > len {
+            panic!("insertion index (is {index}) should be <= len (is {len})");
+        }
+
+        if let Err(error) = self.try_reserve(1) {
+            return Err(TryPushError {
+                value: element,
+                error,
+            });
+        }
+        // A slot is allocated past the end, so this does not allocate.
+        self.insert(index, element);
+        Ok(())
+    }
+
+    /// Appends every element of an iterator, stopping at the first element
+    /// that cannot be stored.
+    ///
+    /// On failure, the elements before the failing one stay in the vector
+    /// and the failing one is returned inside the error. Items the iterator
+    /// has not yielded yet are left in it, so pass `iter.by_ref()` to keep
+    /// them.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkedVec, ChunkedVecSized};
+    /// let mut vec: ChunkedVec<i32, 4> = ChunkedVecSized::new();
+    /// vec.try_extend(0..10).unwrap();
+    /// assert_eq!(vec.len(), 10);
+    /// ```
+    pub fn try_extend<I>(&mut self, iter: I) -> Result<(), TryPushError<T>>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let iter = iter.into_iter();
+        // Reserving for the whole lower bound is only an optimization; if it
+        // fails, elements are still stored one by one until one does not fit.
+        let _ = self.try_reserve(iter.size_hint().0);
+        for item in iter {
+            self.try_push(item)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ChunkedVec, ChunkedVecSized};
+    use std::error::Error;
+
+    /// Every chunk needs 2^58 bytes, which no allocator can provide.
+    type Unallocatable = ChunkedVec<u64, { 1 << 55 }>;
+
+    #[test]
+    fn test_try_push_and_insert() {
+        let mut vec: ChunkedVec<String, 2> = ChunkedVecSized::new();
+        for i in 0..5 {
+            vec.try_push(i.to_string()).unwrap();
+        }
+        vec.try_insert(0, "a".to_string()).unwrap();
+        vec.try_insert(6, "b".to_string()).unwrap();
+        vec.try_insert(3, "c".to_string()).unwrap();
+        assert_eq!(vec, ["a", "0", "1", "c", "2", "3", "4", "b"]);
+        assert_eq!(vec.allocated_capacity(), 8);
+    }
+
+    #[test]
+    fn test_try_push_reuses_retained_chunks() {
+        let mut vec: ChunkedVec<i32, 4> = ChunkedVecSized::new();
+        vec.extend(0..8);
+        vec.clear();
+        vec.try_extend(0..8).unwrap();
+        assert_eq!(vec.allocated_capacity(), 8);
+    }
+
+    #[test]
+    fn test_try_push_returns_value_on_failure() {
+        let mut vec: Unallocatable = ChunkedVecSized::new();
+        let err = vec.try_push(7).unwrap_err();
+        assert_eq!(*err.value(), 7);
+        assert!(err.source().is_some());
+        assert!(err.to_string().starts_with("could not store element"));
+        assert_eq!(format!("{:?}", err).split(' ').next(), Some("TryPushError"));
+        assert_eq!(err.into_value(), 7);
+
+        assert!(vec.is_empty());
+        assert_eq!(vec.allocated_capacity(), 0);
+    }
+
+    #[test]
+    fn test_try_extend_stops_at_failure() {
+        let mut vec: Unallocatable = ChunkedVecSized::new();
+        let mut iter = 10..20;
+        let err = vec.try_extend(iter.by_ref()).unwrap_err();
+        assert_eq!(err.into_value(), 10);
+        assert_eq!(iter, 11..20);
+        assert!(vec.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "insertion index (is 2) should be <= len (is 1)")]
+    fn test_try_insert_out_of_bounds() {
+        let mut vec: ChunkedVec<i32, 2> = ChunkedVecSized::new();
+        vec.push(1);
+        let _ = vec.try_insert(2, 0);
+    }
+}
@@ -85,6 +85,77 @@ impl<T, const N: usize> ChunkedVec<T, N> {
         }
     }
 
+    /// Returns a reference to the first element, or `None` if the vector is
+    /// empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let mut vec = ChunkedVec::<i32>::new();
+    /// assert_eq!(vec.first(), None);
+    /// vec.push(1);
+    /// vec.push(2);
+    /// assert_eq!(vec.first(), Some(&1));
+    /// ```
+    #[inline]
+    pub fn first(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Returns a mutable reference to the first element, or `None` if the
+    /// vector is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let mut vec = ChunkedVec::<i32>::new();
+    /// vec.push(1);
+    /// if let Some(x) = vec.first_mut() {
+    ///     *x = 10;
+    /// }
+    /// assert_eq!(vec[0], 10);
+    /// ```
+    #[inline]
+    pub fn first_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(0)
+    }
+
+    /// Returns a reference to the last element, or `None` if the vector is
+    /// empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let mut vec = ChunkedVec::<i32>::new();
+    /// assert_eq!(vec.last(), None);
+    /// vec.push(1);
+    /// vec.push(2);
+    /// assert_eq!(vec.last(), Some(&2));
+    /// ```
+    #[inline]
+    pub fn last(&self) -> Option<&T> {
+        self.get(self.len.wrapping_sub(1))
+    }
+
+    /// Returns a mutable reference to the last element, or `None` if the
+    /// vector is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let mut vec = ChunkedVec::<i32>::new();
+    /// vec.push(1);
+    /// vec.push(2);
+    /// if let Some(x) = vec.last_mut() {
+    ///     *x = 20;
+    /// }
+    /// assert_eq!(vec[1], 20);
+    /// ```
+    #[inline]
+    pub fn last_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(self.len.wrapping_sub(1))
+    }
+
     /// Gets the chunk index and offset for a given element index.
     ///
     /// # Returns
@@ -118,6 +189,26 @@ impl<T, const N: usize> ChunkedVec<T, N> {
     pub(crate) unsafe fn get_elem_mut_ptr(&mut self, index: usize, offset: usize) -> *mut T {
         self.get_chunk_mut_ptr(index).add(offset).cast()
     }
+
+    /// Returns pointers to the elements at logical indices `a` and `b`.
+    ///
+    /// Pointers into the same chunk are derived from one borrow of that
+    /// chunk, so both stay valid together.
+    #[inline]
+    #[must_use]
+    pub(crate) unsafe fn get_elem_mut_ptr_pair(&mut self, a: usize, b: usize) -> (*mut T, *mut T) {
+        let (a_chunk, a_off) = self.chunk_and_offset(a);
+        let (b_chunk, b_off) = self.chunk_and_offset(b);
+        if a_chunk == b_chunk {
+            let base = self.get_chunk_mut_ptr(a_chunk);
+            (base.add(a_off), base.add(b_off))
+        } else {
+            (
+                self.get_elem_mut_ptr(a_chunk, a_off),
+                self.get_elem_mut_ptr(b_chunk, b_off),
+            )
+        }
+    }
 }
 
 impl<T, const N: usize> Index<usize> for ChunkedVec<T, N> {
@@ -199,4 +290,22 @@ mod test {
         assert_eq!(vec[0], 10);
         assert_eq!(vec.get_mut(2), None);
     }
+
+    #[test]
+    fn test_first_last() {
+        let mut vec = ChunkedVecSized::<i32, 2>::new();
+        assert_eq!(vec.first(), None);
+        assert_eq!(vec.last(), None);
+        assert_eq!(vec.first_mut(), None);
+        assert_eq!(vec.last_mut(), None);
+
+        vec.extend([1, 2, 3]);
+        assert_eq!(vec.first(), Some(&1));
+        assert_eq!(vec.last(), Some(&3));
+
+        *vec.first_mut().unwrap() = 10;
+        *vec.last_mut().unwrap() = 30;
+        assert_eq!(vec[0], 10);
+        assert_eq!(vec[2], 30);
+    }
 }
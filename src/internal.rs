@@ -1,12 +1,77 @@
+use crate::{Chunk, ChunkedVec};
 use std::array::from_fn;
+use std::collections::TryReserveError;
 use std::mem::MaybeUninit;
-use crate::{Chunk, ChunkedVec};
+use std::ops::{Bound, Range, RangeBounds};
+
+/// Converts `range` into a concrete `start..end` within a sequence of length
+/// `len`.
+///
+/// Panics with the same messages as slice indexing when the range is
+/// decreasing or out of bounds.
+pub(crate) fn resolve_range<R: RangeBounds<usize>>(range: R, len: usize) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start
+            .checked_add(1)
+            .unwrap_or_else(|| panic!("attempted to index slice from after maximum usize")),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end
+            .checked_add(1)
+            .unwrap_or_else(|| panic!("attempted to index slice up to maximum usize")),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+
+    if start > end {
+        panic!("slice index starts at {start} but ends at {end}");
+    }
+    if end > len {
+        panic!("range end index {end} out of range for slice of length {len}");
+    }
+    start..end
+}
 
 impl<T, const N: usize> ChunkedVec<T, N> {
-    pub(crate) fn create_new_chunk(value: T) -> Chunk<T, N> {
+    pub(crate) fn create_empty_chunk() -> Chunk<T, N> {
         let arr: [MaybeUninit<T>; N] = from_fn(|_| MaybeUninit::uninit());
-        let mut chunk: Chunk<T, N> = Box::new(arr);
+        Box::new(arr)
+    }
+
+    /// Allocates an empty chunk, reporting allocation failure instead of
+    /// aborting.
+    pub(crate) fn try_create_empty_chunk() -> Result<Chunk<T, N>, TryReserveError> {
+        let mut storage: Vec<MaybeUninit<T>> = Vec::new();
+        storage.try_reserve_exact(N)?;
+        // Safety: the capacity is at least `N`, and `MaybeUninit` slots need
+        // no initialization.
+        unsafe { storage.set_len(N) };
+        match storage.into_boxed_slice().try_into() {
+            Ok(chunk) => Ok(chunk),
+            Err(_) => unreachable!("the storage holds exactly N slots"),
+        }
+    }
+
+    pub(crate) fn create_new_chunk(value: T) -> Chunk<T, N> {
+        let mut chunk = Self::create_empty_chunk();
         chunk[0].write(value);
         chunk
     }
+
+    /// Clones exactly `N` elements into a new, fully initialized chunk.
+    ///
+    /// The elements are cloned in bulk by `<[T]>::to_vec`, which also drops
+    /// the clones made so far if one of them panics.
+    pub(crate) fn create_chunk_from_slice(src: &[T]) -> Chunk<T, N>
+    where
+        T: Clone,
+    {
+        assert_eq!(src.len(), N);
+        let boxed: Box<[T]> = src.to_vec().into_boxed_slice();
+        // Safety: a boxed slice of `N` elements has the layout of `[T; N]`,
+        // and `MaybeUninit<T>` has the layout of `T`.
+        unsafe { Box::from_raw(Box::into_raw(boxed).cast::<[MaybeUninit<T>; N]>()) }
+    }
 }
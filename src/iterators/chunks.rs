@@ -0,0 +1,355 @@
+use std::iter::FusedIterator;
+use std::slice;
+
+use likely_stable::unlikely;
+
+use crate::ChunkedVec;
+
+/// An iterator over the initialized chunks of a ChunkedVec, as slices.
+///
+/// This struct is created by the [`chunks`] method on [`ChunkedVec`].
+/// See its documentation for more.
+///
+/// [`chunks`]: ChunkedVec::chunks
+pub struct Chunks<'a, T, const N: usize> {
+    pub(crate) vec: &'a ChunkedVec<T, N>,
+    /// Index of the next chunk yielded from the front.
+    pub(crate) front: usize,
+    /// Index one past the next chunk yielded from the back.
+    pub(crate) back: usize,
+}
+
+// Safety: the iterator only hands out shared slices, like
+// `slice::Iter`.
+unsafe impl<T: Sync, const N: usize> Send for Chunks<'_, T, N> {}
+
+// Safety: see above.
+unsafe impl<T: Sync, const N: usize> Sync for Chunks<'_, T, N> {}
+
+/// An iterator over the initialized chunks of a ChunkedVec, as mutable
+/// slices.
+///
+/// This struct is created by the [`chunks_mut`] method on [`ChunkedVec`].
+/// See its documentation for more.
+///
+/// [`chunks_mut`]: ChunkedVec::chunks_mut
+pub struct ChunksMut<'a, T, const N: usize> {
+    pub(crate) vec: &'a mut ChunkedVec<T, N>,
+    /// Index of the next chunk yielded from the front.
+    pub(crate) front: usize,
+    /// Index one past the next chunk yielded from the back.
+    pub(crate) back: usize,
+}
+
+// Safety: the iterator hands out mutable slices, like
+// `slice::ChunksMut`.
+unsafe impl<T: Send, const N: usize> Send for ChunksMut<'_, T, N> {}
+
+// Safety: a shared reference to it only gives shared access to the
+// elements.
+unsafe impl<T: Sync, const N: usize> Sync for ChunksMut<'_, T, N> {}
+
+impl<T, const N: usize> ChunkedVec<T, N> {
+    /// Returns the number of initialized elements in chunk `chunk_idx`.
+    ///
+    /// `chunk_idx` must be below `self.len.div_ceil(N)`.
+    #[inline]
+    fn chunk_len(&self, chunk_idx: usize) -> usize {
+        (self.len - chunk_idx * N).min(N)
+    }
+
+    /// Returns the initialized elements of chunk `chunk_idx` as a slice, or
+    /// `None` if the chunk holds no elements.
+    ///
+    /// Every chunk but the last one holds exactly `N` elements; the last one
+    /// holds the remaining `len - (chunk_count - 1) * N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkedVec, ChunkedVecSized};
+    /// let mut vec: ChunkedVec<i32, 4> = ChunkedVecSized::new();
+    /// vec.extend(0..6);
+    /// assert_eq!(vec.chunk(0), Some(&[0, 1, 2, 3][..]));
+    /// assert_eq!(vec.chunk(1), Some(&[4, 5][..]));
+    /// assert_eq!(vec.chunk(2), None);
+    /// ```
+    pub fn chunk(&self, chunk_idx: usize) -> Option<&[T]> {
+        if chunk_idx >= self.len.div_ceil(N) {
+            return None;
+        }
+        // Safety: the first `chunk_len` slots of the chunk are initialized.
+        unsafe {
+            Some(slice::from_raw_parts(
+                self.get_chunk_ptr(chunk_idx),
+                self.chunk_len(chunk_idx),
+            ))
+        }
+    }
+
+    /// Returns the initialized elements of chunk `chunk_idx` as a mutable
+    /// slice, or `None` if the chunk holds no elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkedVec, ChunkedVecSized};
+    /// let mut vec: ChunkedVec<i32, 4> = ChunkedVecSized::new();
+    /// vec.extend(0..6);
+    /// vec.chunk_mut(1).unwrap().fill(9);
+    /// assert_eq!(vec, [0, 1, 2, 3, 9, 9]);
+    /// ```
+    pub fn chunk_mut(&mut self, chunk_idx: usize) -> Option<&mut [T]> {
+        if chunk_idx >= self.len.div_ceil(N) {
+            return None;
+        }
+        let len = self.chunk_len(chunk_idx);
+        // Safety: the first `len` slots of the chunk are initialized.
+        unsafe {
+            Some(slice::from_raw_parts_mut(
+                self.get_chunk_mut_ptr(chunk_idx),
+                len,
+            ))
+        }
+    }
+
+    /// Returns an iterator over the initialized chunks as contiguous slices.
+    ///
+    /// Every slice but the last one has exactly `N` elements; the last one
+    /// is shortened to the elements actually stored in it. Concatenating the
+    /// slices gives the elements of the vector in order, so they can be
+    /// handed to slice-based routines without copying.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::io::Write;
+    /// use chunked_vec::{ChunkedVec, ChunkedVecSized};
+    ///
+    /// let mut vec: ChunkedVec<u8, 4> = ChunkedVecSized::new();
+    /// vec.extend(b"hello world".iter().copied());
+    ///
+    /// let lens: Vec<usize> = vec.chunks().map(|c| c.len()).collect();
+    /// assert_eq!(lens, [4, 4, 3]);
+    ///
+    /// let mut out = Vec::new();
+    /// for chunk in vec.chunks() {
+    ///     out.write_all(chunk).unwrap();
+    /// }
+    /// assert_eq!(out, b"hello world");
+    /// ```
+    pub fn chunks(&self) -> Chunks<'_, T, N> {
+        Chunks {
+            vec: self,
+            front: 0,
+            back: self.len.div_ceil(N),
+        }
+    }
+
+    /// Returns an iterator over the initialized chunks as contiguous mutable
+    /// slices.
+    ///
+    /// The slices are laid out exactly as for [`chunks`].
+    ///
+    /// [`chunks`]: ChunkedVec::chunks
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkedVec, ChunkedVecSized};
+    ///
+    /// let mut vec: ChunkedVec<i32, 2> = ChunkedVecSized::new();
+    /// vec.extend([1, 2, 3, 4, 5]);
+    /// for chunk in vec.chunks_mut() {
+    ///     chunk.reverse();
+    /// }
+    /// assert_eq!(vec, [2, 1, 4, 3, 5]);
+    /// ```
+    pub fn chunks_mut(&mut self) -> ChunksMut<'_, T, N> {
+        let back = self.len.div_ceil(N);
+        ChunksMut {
+            vec: self,
+            front: 0,
+            back,
+        }
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for Chunks<'a, T, N> {
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if unlikely(self.front == self.back) {
+            return None;
+        }
+
+        let chunk = self.vec.chunk(self.front);
+        self.front += 1;
+        chunk
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        (remaining, Some(remaining))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.front = self.front.saturating_add(n).min(self.back);
+        self.next()
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for Chunks<'_, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if unlikely(self.front == self.back) {
+            return None;
+        }
+
+        self.back -= 1;
+        self.vec.chunk(self.back)
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for Chunks<'_, T, N> {}
+
+impl<T, const N: usize> FusedIterator for Chunks<'_, T, N> {}
+
+impl<T, const N: usize> Clone for Chunks<'_, T, N> {
+    fn clone(&self) -> Self {
+        Chunks {
+            vec: self.vec,
+            front: self.front,
+            back: self.back,
+        }
+    }
+}
+
+impl<'a, T, const N: usize> ChunksMut<'a, T, N> {
+    /// Returns chunk `chunk_idx` as a mutable slice with the iterator's
+    /// lifetime.
+    ///
+    /// Must only be called with an index in `[front, back)`, once per index.
+    #[inline]
+    fn take_chunk(&mut self, chunk_idx: usize) -> &'a mut [T] {
+        let len = self.vec.chunk_len(chunk_idx);
+        // Safety: the chunk is initialized up to `len`, and every chunk is
+        // a separate allocation that is handed out at most once.
+        unsafe { slice::from_raw_parts_mut(self.vec.get_chunk_mut_ptr(chunk_idx), len) }
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for ChunksMut<'a, T, N> {
+    type Item = &'a mut [T];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if unlikely(self.front == self.back) {
+            return None;
+        }
+
+        let chunk = self.take_chunk(self.front);
+        self.front += 1;
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        (remaining, Some(remaining))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.front = self.front.saturating_add(n).min(self.back);
+        self.next()
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for ChunksMut<'_, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if unlikely(self.front == self.back) {
+            return None;
+        }
+
+        self.back -= 1;
+        Some(self.take_chunk(self.back))
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for ChunksMut<'_, T, N> {}
+
+impl<T, const N: usize> FusedIterator for ChunksMut<'_, T, N> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ChunkedVec, ChunkedVecSized};
+
+    fn check_layout<const N: usize>(len: usize) {
+        let mut vec: ChunkedVec<usize, N> = ChunkedVecSized::new();
+        vec.extend(0..len);
+
+        let chunks: Vec<&[usize]> = vec.chunks().collect();
+        assert_eq!(chunks.len(), len.div_ceil(N));
+        assert_eq!(vec.chunks().len(), chunks.len());
+        for (i, chunk) in chunks.iter().enumerate() {
+            assert_eq!(Some(*chunk), vec.chunk(i));
+            if i + 1 < chunks.len() {
+                assert_eq!(chunk.len(), N);
+            }
+        }
+        assert_eq!(chunks.concat(), (0..len).collect::<Vec<_>>());
+
+        let rev: Vec<&[usize]> = vec.chunks().rev().collect();
+        assert!(rev.iter().eq(chunks.iter().rev()));
+        assert_eq!(vec.chunk(chunks.len()), None);
+    }
+
+    #[test]
+    fn test_chunks_layout() {
+        for len in 0..20 {
+            check_layout::<1>(len);
+            check_layout::<3>(len);
+            check_layout::<4>(len);
+            check_layout::<64>(len);
+        }
+    }
+
+    #[test]
+    fn test_chunks_ignore_retained_capacity() {
+        let mut vec: ChunkedVec<i32, 4> = ChunkedVecSized::new();
+        vec.extend(0..10);
+        vec.truncate(4);
+        assert_eq!(vec.allocated_capacity(), 12);
+        assert_eq!(vec.chunks().collect::<Vec<_>>(), [&[0, 1, 2, 3][..]]);
+
+        vec.clear();
+        assert_eq!(vec.chunks().next(), None);
+        assert_eq!(vec.chunks_mut().next(), None);
+        assert_eq!(vec.chunk(0), None);
+    }
+
+    #[test]
+    fn test_chunks_mut_double_ended() {
+        let mut vec: ChunkedVec<i32, 3> = ChunkedVecSized::new();
+        vec.extend(0..8);
+
+        let mut chunks = vec.chunks_mut();
+        let first = chunks.next().unwrap();
+        let last = chunks.next_back().unwrap();
+        assert_eq!(chunks.len(), 1);
+        let middle = chunks.next().unwrap();
+        assert!(chunks.next().is_none());
+        assert!(chunks.next_back().is_none());
+
+        first[0] = -1;
+        middle[0] = -2;
+        last.fill(-3);
+        assert_eq!(vec, [-1, 1, 2, -2, 4, 5, -3, -3]);
+    }
+
+    #[test]
+    fn test_chunks_nth() {
+        let vec: ChunkedVec<i32, 2> = (0..9).collect();
+        let mut chunks = vec.chunks();
+        assert_eq!(chunks.nth(2), Some(&[4, 5][..]));
+        assert_eq!(chunks.nth(1), Some(&[8][..]));
+        assert_eq!(chunks.next(), None);
+
+        let mut vec = vec;
+        assert_eq!(vec.chunks_mut().nth(3), Some(&mut [6, 7][..]));
+        assert_eq!(vec.chunks_mut().nth(5), None);
+    }
+}
@@ -0,0 +1,313 @@
+use std::iter::FusedIterator;
+use std::ops::RangeBounds;
+use std::ptr;
+
+use likely_stable::unlikely;
+
+use crate::internal::resolve_range;
+use crate::ChunkedVec;
+
+/// A draining iterator over a range of a ChunkedVec.
+///
+/// This struct is created by the [`drain`] method on [`ChunkedVec`].
+/// See its documentation for more.
+///
+/// [`drain`]: ChunkedVec::drain
+pub struct Drain<'a, T, const N: usize> {
+    pub(crate) vec: &'a mut ChunkedVec<T, N>,
+    /// Logical index of the next element yielded from the front.
+    pub(crate) front: usize,
+    /// Logical index one past the next element yielded from the back.
+    pub(crate) back: usize,
+    /// Logical index of the first element kept after the drained range.
+    pub(crate) tail_start: usize,
+    pub(crate) tail_len: usize,
+}
+
+// Safety: the iterator moves elements out of the vector, like
+// `vec::Drain`.
+unsafe impl<T: Send, const N: usize> Send for Drain<'_, T, N> {}
+
+// Safety: a shared reference to it only gives shared access to the
+// elements.
+unsafe impl<T: Sync, const N: usize> Sync for Drain<'_, T, N> {}
+
+impl<T, const N: usize> ChunkedVec<T, N> {
+    /// Removes the specified range from the vector in bulk, returning all
+    /// removed elements as an iterator.
+    ///
+    /// When the iterator is dropped, any elements it did not yield are
+    /// dropped and the elements after the range are shifted down to close
+    /// the gap, exactly like [`Vec::drain`]. Allocated chunks are retained.
+    ///
+    /// If the iterator is leaked (e.g. with [`std::mem::forget`]), the vector
+    /// is left truncated to the start of the range: the drained and tail
+    /// elements are leaked, but the vector stays valid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is greater than its end, or if the
+    /// end is greater than the length of the vector.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let mut vec = chunked_vec![1, 2, 3, 4, 5];
+    ///
+    /// let drained: Vec<i32> = vec.drain(1..3).collect();
+    /// assert_eq!(drained, [2, 3]);
+    /// assert_eq!(vec, [1, 4, 5]);
+    ///
+    /// // A full range clears the vector
+    /// vec.drain(..);
+    /// assert!(vec.is_empty());
+    /// ```
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_, T, N>
+    where
+        R: RangeBounds<usize>,
+    {
+        let len = self.len;
+        let range = resolve_range(range, len);
+
+        // Until the Drain is dropped, only the prefix is owned by the vector.
+        self.len = range.start;
+        Drain {
+            vec: self,
+            front: range.start,
+            back: range.end,
+            tail_start: range.end,
+            tail_len: len - range.end,
+        }
+    }
+}
+
+impl<T, const N: usize> Drain<'_, T, N> {
+    /// Shifts the tail down to the end of the kept prefix and restores the
+    /// vector's length.
+    fn move_tail(&mut self) {
+        let start = self.vec.len;
+        // Safety: `[tail_start, tail_start + tail_len)` is initialized and
+        // `start <= tail_start`; the vacated slots beyond the new length are
+        // no longer counted.
+        unsafe {
+            self.vec
+                .move_range_down(self.tail_start, start, self.tail_len);
+        }
+        self.vec.len = start + self.tail_len;
+    }
+}
+
+impl<T, const N: usize> Iterator for Drain<'_, T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if unlikely(self.front == self.back) {
+            return None;
+        }
+
+        let (chunk_idx, offset) = self.vec.chunk_and_offset(self.front);
+        self.front += 1;
+        // Safety: the slot is within the drained range and not yet yielded.
+        unsafe { Some(ptr::read(self.vec.get_elem_ptr(chunk_idx, offset))) }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for Drain<'_, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if unlikely(self.front == self.back) {
+            return None;
+        }
+
+        self.back -= 1;
+        let (chunk_idx, offset) = self.vec.chunk_and_offset(self.back);
+        // Safety: the slot is within the drained range and not yet yielded.
+        unsafe { Some(ptr::read(self.vec.get_elem_ptr(chunk_idx, offset))) }
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for Drain<'_, T, N> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.back - self.front
+    }
+}
+
+impl<T, const N: usize> FusedIterator for Drain<'_, T, N> {}
+
+/// Drops the elements that were not yielded and closes the gap.
+impl<T, const N: usize> Drop for Drain<'_, T, N> {
+    fn drop(&mut self) {
+        /// Moves the tail into place even if dropping an element panics.
+        struct TailGuard<'r, 'a, T, const N: usize>(&'r mut Drain<'a, T, N>);
+
+        impl<T, const N: usize> Drop for TailGuard<'_, '_, T, N> {
+            fn drop(&mut self) {
+                self.0.move_tail();
+            }
+        }
+
+        let (front, back) = (self.front, self.back);
+        self.front = back;
+
+        let guard = TailGuard(self);
+        // Safety: `[front, back)` holds exactly the elements not yet yielded.
+        unsafe { guard.0.vec.drop_range(front, back) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    use crate::{ChunkedVec, ChunkedVecSized};
+
+    struct Counted<'a> {
+        id: usize,
+        drops: &'a Cell<usize>,
+    }
+
+    impl Drop for Counted<'_> {
+        fn drop(&mut self) {
+            self.drops.set(self.drops.get() + 1);
+        }
+    }
+
+    fn counted<const N: usize>(len: usize, drops: &Cell<usize>) -> ChunkedVec<Counted<'_>, N> {
+        let mut vec = ChunkedVecSized::new();
+        for id in 0..len {
+            vec.push(Counted { id, drops });
+        }
+        vec
+    }
+
+    fn ids<const N: usize>(vec: &ChunkedVec<Counted<'_>, N>) -> Vec<usize> {
+        vec.iter().map(|c| c.id).collect()
+    }
+
+    #[test]
+    fn test_drain_matches_vec() {
+        for len in 0..12 {
+            for start in 0..=len {
+                for end in start..=len {
+                    let mut vec: ChunkedVec<usize, 3> = (0..len).collect();
+                    let mut expected: Vec<usize> = (0..len).collect();
+
+                    let drained: Vec<usize> = vec.drain(start..end).collect();
+                    let expected_drained: Vec<usize> = expected.drain(start..end).collect();
+
+                    assert_eq!(drained, expected_drained);
+                    assert!(vec.iter().eq(expected.iter()));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_drain_range_kinds() {
+        let mut vec: ChunkedVec<i32, 2> = (0..10).collect();
+        assert_eq!(vec.drain(..2).collect::<Vec<_>>(), [0, 1]);
+        assert_eq!(vec.drain(6..).collect::<Vec<_>>(), [8, 9]);
+        assert_eq!(vec.drain(1..=2).collect::<Vec<_>>(), [3, 4]);
+        assert_eq!(vec, [2, 5, 6, 7]);
+        assert_eq!(vec.allocated_capacity(), 10); // chunks are retained
+    }
+
+    #[test]
+    fn test_drain_double_ended() {
+        let mut vec: ChunkedVec<i32, 3> = (0..10).collect();
+        let mut drain = vec.drain(2..9);
+        assert_eq!(drain.len(), 7);
+        assert_eq!(drain.next_back(), Some(8));
+        assert_eq!(drain.next(), Some(2));
+        assert_eq!(drain.rev().collect::<Vec<_>>(), [7, 6, 5, 4, 3]);
+        assert_eq!(vec, [0, 1, 9]);
+    }
+
+    #[test]
+    fn test_drain_partial_consumption_drop_count() {
+        let drops = Cell::new(0);
+        let mut vec = counted::<3>(10, &drops);
+
+        let mut drain = vec.drain(2..8);
+        assert_eq!(drain.next().map(|c| c.id), Some(2));
+        assert_eq!(drain.next_back().map(|c| c.id), Some(7));
+        assert_eq!(drops.get(), 2);
+
+        // The 4 unyielded elements are dropped with the iterator
+        drop(drain);
+        assert_eq!(drops.get(), 6);
+        assert_eq!(ids(&vec), [0, 1, 8, 9]);
+
+        drop(vec);
+        assert_eq!(drops.get(), 10);
+    }
+
+    #[test]
+    fn test_drain_forget_leaves_valid_prefix() {
+        let drops = Cell::new(0);
+        let mut vec = counted::<3>(10, &drops);
+
+        let mut drain = vec.drain(4..6);
+        drain.next();
+        std::mem::forget(drain);
+
+        assert_eq!(drops.get(), 1);
+        assert_eq!(ids(&vec), [0, 1, 2, 3]);
+
+        vec.push(Counted {
+            id: 42,
+            drops: &drops,
+        });
+        assert_eq!(ids(&vec), [0, 1, 2, 3, 42]);
+
+        drop(vec);
+        // The 4 leaked elements (5 and the tail 6..10) are never dropped
+        assert_eq!(drops.get(), 6);
+    }
+
+    #[test]
+    fn test_drain_panicking_drop_still_closes_gap() {
+        struct PanicOnDrop(usize);
+        impl Drop for PanicOnDrop {
+            fn drop(&mut self) {
+                if self.0 == 3 {
+                    panic!("drop panic");
+                }
+            }
+        }
+
+        let mut vec: ChunkedVec<PanicOnDrop, 2> = ChunkedVecSized::new();
+        for i in 0..8 {
+            vec.push(PanicOnDrop(i));
+        }
+
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            vec.drain(2..5);
+        }));
+        assert!(result.is_err());
+
+        let values: Vec<usize> = vec.iter().map(|p| p.0).collect();
+        assert_eq!(values, [0, 1, 5, 6, 7]);
+    }
+
+    #[test]
+    #[should_panic(expected = "range end index 4 out of range for slice of length 3")]
+    fn test_drain_out_of_bounds() {
+        let mut vec: ChunkedVec<i32, 2> = (0..3).collect();
+        vec.drain(1..4);
+    }
+
+    #[test]
+    #[should_panic(expected = "slice index starts at 2 but ends at 1")]
+    fn test_drain_decreasing_range() {
+        let mut vec: ChunkedVec<i32, 2> = (0..3).collect();
+        #[allow(clippy::reversed_empty_ranges)]
+        vec.drain(2..1);
+    }
+}
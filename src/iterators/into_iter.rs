@@ -1,5 +1,5 @@
 use likely_stable::unlikely;
-use std::{mem::MaybeUninit, ptr};
+use std::{iter::FusedIterator, mem::MaybeUninit, ptr};
 
 use crate::ChunkedVec;
 
@@ -25,9 +25,19 @@ pub struct IntoIter<T, const N: usize> {
     pub(crate) vec: ChunkedVec<T, N>,
     pub(crate) chunk_idx: usize,
     pub(crate) offset: usize,
+    pub(crate) back_chunk_idx: usize,
+    pub(crate) back_offset: usize,
     pub(crate) remaining: usize,
 }
 
+// Safety: the iterator owns the elements it has not yielded yet, like
+// `vec::IntoIter`.
+unsafe impl<T: Send, const N: usize> Send for IntoIter<T, N> {}
+
+// Safety: a shared reference to it only gives shared access to the
+// elements.
+unsafe impl<T: Sync, const N: usize> Sync for IntoIter<T, N> {}
+
 /// Implementation of IntoIterator for ChunkedVec, enabling use in for loops.
 ///
 /// This implementation consumes the ChunkedVec, taking ownership of its elements.
@@ -36,11 +46,14 @@ impl<T, const N: usize> IntoIterator for ChunkedVec<T, N> {
     type IntoIter = IntoIter<T, N>;
 
     fn into_iter(self) -> Self::IntoIter {
+        let (back_chunk_idx, back_offset) = self.chunk_and_offset(self.len);
         IntoIter {
             remaining: self.len(),
             vec: self,
             chunk_idx: 0,
             offset: 0,
+            back_chunk_idx,
+            back_offset,
         }
     }
 }
@@ -57,22 +70,136 @@ impl<T, const N: usize> IntoIter<T, N> {
         self.remaining -= 1;
     }
 
+    /// Moves the back cursor to the previous position.
+    #[inline]
+    unsafe fn retreat_position(&mut self) {
+        if unlikely(self.back_offset == 0) {
+            self.back_chunk_idx -= 1;
+            self.back_offset = N;
+        }
+        self.back_offset -= 1;
+        self.remaining -= 1;
+    }
+
     /// Returns a pointer to the current element.
     #[inline]
     fn current_ptr(&mut self) -> &mut MaybeUninit<T> {
         &mut self.vec.data[self.chunk_idx][self.offset]
     }
 
+    /// Returns a pointer to the element under the back cursor.
+    #[inline]
+    fn back_ptr(&mut self) -> &mut MaybeUninit<T> {
+        &mut self.vec.data[self.back_chunk_idx][self.back_offset]
+    }
+
+    /// Moves the front cursor forward by `n` positions in *O*(1).
+    ///
+    /// `n` must not exceed `remaining`. The skipped elements are not dropped.
+    #[inline]
+    fn skip_front(&mut self, n: usize) {
+        let pos = self.offset + n;
+        self.chunk_idx += pos / N;
+        self.offset = pos % N;
+        self.remaining -= n;
+    }
+
+    /// Moves the back cursor backward by `n` positions in *O*(1).
+    ///
+    /// `n` must not exceed `remaining`. The skipped elements are not dropped.
+    #[inline]
+    fn skip_back(&mut self, n: usize) {
+        let pos = self.back_chunk_idx * N + self.back_offset - n;
+        self.back_chunk_idx = pos / N;
+        self.back_offset = pos % N;
+        self.remaining -= n;
+    }
+
+    /// Steps the front cursor over the next run of at most `max` elements
+    /// that lie in one chunk, and returns the run's raw parts.
+    ///
+    /// The caller takes ownership of the returned elements. `max` must be
+    /// non-zero and not exceed `remaining`.
+    #[inline]
+    fn take_front_run(&mut self, max: usize) -> (*mut T, usize) {
+        let len = (N - self.offset).min(max);
+        // Safety: the front cursor points at a live element.
+        let ptr = unsafe { self.vec.get_elem_mut_ptr(self.chunk_idx, self.offset) };
+        self.skip_front(len);
+        (ptr, len)
+    }
+
+    /// Steps the back cursor over the previous run of at most `max` elements
+    /// that lie in one chunk, and returns the run's raw parts.
+    ///
+    /// The caller takes ownership of the returned elements. `max` must be
+    /// non-zero and not exceed `remaining`.
+    #[inline]
+    fn take_back_run(&mut self, max: usize) -> (*mut T, usize) {
+        if self.back_offset == 0 {
+            self.back_chunk_idx -= 1;
+            self.back_offset = N;
+        }
+        let len = self.back_offset.min(max);
+        self.skip_back(len);
+        // Safety: after stepping back, the back cursor points at a live element.
+        let ptr = unsafe {
+            self.vec
+                .get_elem_mut_ptr(self.back_chunk_idx, self.back_offset)
+        };
+        (ptr, len)
+    }
+
+    /// Drops the next `n` elements from the front, one chunk run at a time.
+    fn drop_front(&mut self, n: usize) {
+        if !std::mem::needs_drop::<T>() {
+            self.skip_front(n);
+            return;
+        }
+
+        let mut left = n;
+        while left > 0 {
+            let (ptr, len) = self.take_front_run(left);
+            left -= len;
+            unsafe { ptr::drop_in_place(ptr::slice_from_raw_parts_mut(ptr, len)) };
+        }
+    }
+
+    /// Drops the previous `n` elements from the back, one chunk run at a time.
+    fn drop_back(&mut self, n: usize) {
+        if !std::mem::needs_drop::<T>() {
+            self.skip_back(n);
+            return;
+        }
+
+        let mut left = n;
+        while left > 0 {
+            let (ptr, len) = self.take_back_run(left);
+            left -= len;
+            unsafe { ptr::drop_in_place(ptr::slice_from_raw_parts_mut(ptr, len)) };
+        }
+    }
+
     /// Drops all remaining elements without returning them.
     /// More efficient than calling next() repeatedly.
+    ///
+    /// The live elements are always the `remaining` slots starting at the
+    /// front cursor, however many were taken from the back.
     fn drop_remaining(&mut self) {
-        while self.remaining > 0 {
-            unsafe {
-                self.current_ptr().assume_init_drop();
-                *self.current_ptr() = MaybeUninit::uninit();
-                self.advance_position();
-            }
-        }
+        self.drop_front(self.remaining);
+    }
+}
+
+/// Owns the not-yet-yielded part of a run taken by `fold` or `rfold`, and
+/// drops it if the closure unwinds.
+struct RunGuard<T> {
+    ptr: *mut T,
+    len: usize,
+}
+
+impl<T> Drop for RunGuard<T> {
+    fn drop(&mut self) {
+        unsafe { ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.ptr, self.len)) };
     }
 }
 
@@ -96,16 +223,109 @@ impl<T, const N: usize> Iterator for IntoIter<T, N> {
         let remaining = self.remaining;
         (remaining, Some(remaining))
     }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.remaining
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.drop_front(n.min(self.remaining));
+        self.next()
+    }
+
+    fn fold<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let mut acc = init;
+        while self.remaining > 0 {
+            let (ptr, len) = self.take_front_run(self.remaining);
+            let mut run = RunGuard { ptr, len };
+            while run.len > 0 {
+                // Safety: the guard owns the slots it still covers; shrinking
+                // it before calling `f` hands this one over to the closure.
+                let value = unsafe { ptr::read(run.ptr) };
+                run.ptr = unsafe { run.ptr.add(1) };
+                run.len -= 1;
+                acc = f(acc, value);
+            }
+        }
+        acc
+    }
 }
 
+impl<T, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if unlikely(self.remaining == 0) {
+            return None;
+        }
+
+        unsafe {
+            self.retreat_position();
+            let value = ptr::read(self.back_ptr().as_ptr());
+            *self.back_ptr() = MaybeUninit::uninit();
+            Some(value)
+        }
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.drop_back(n.min(self.remaining));
+        self.next_back()
+    }
+
+    fn rfold<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let mut acc = init;
+        while self.remaining > 0 {
+            let (ptr, len) = self.take_back_run(self.remaining);
+            let mut run = RunGuard { ptr, len };
+            while run.len > 0 {
+                // Safety: as in `fold`, taking from the end of the run.
+                run.len -= 1;
+                let value = unsafe { ptr::read(run.ptr.add(run.len)) };
+                acc = f(acc, value);
+            }
+        }
+        acc
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for IntoIter<T, N> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.remaining
+    }
+}
+
+impl<T, const N: usize> FusedIterator for IntoIter<T, N> {}
+
 /// Implementation of Drop for IntoIter to handle partial consumption correctly.
 impl<T, const N: usize> Drop for IntoIter<T, N> {
     fn drop(&mut self) {
-        // Drop all remaining elements
-        self.drop_remaining();
+        /// Drops the rest of the elements if one of their destructors panics.
+        struct DropGuard<'a, T, const N: usize>(&'a mut IntoIter<T, N>);
+
+        impl<T, const N: usize> Drop for DropGuard<'_, T, N> {
+            fn drop(&mut self) {
+                self.0.drop_remaining();
+            }
+        }
 
-        // Prevent ChunkedVec's Drop from trying to drop elements again
+        // Prevent ChunkedVec's Drop from trying to drop elements again. The
+        // iterator drops the ones it still owns, even if one of them panics.
         self.vec.len = 0;
+
+        let guard = DropGuard(self);
+        guard.0.drop_remaining();
+        std::mem::forget(guard);
     }
 }
 
@@ -126,4 +346,142 @@ mod tests {
         assert_eq!(iter.next(), Some(3));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn test_into_iter_rev() {
+        let vec: ChunkedVec<String, 3> = (0..7).map(|i| i.to_string()).collect();
+        let reversed: Vec<String> = vec.into_iter().rev().collect();
+        assert_eq!(reversed, ["6", "5", "4", "3", "2", "1", "0"]);
+    }
+
+    #[test]
+    fn test_into_iter_partial_drop_from_both_ends() {
+        use std::rc::Rc;
+
+        let marker = Rc::new(());
+        for len in 0..10 {
+            for from_front in 0..=len {
+                for from_back in 0..=(len - from_front) {
+                    let vec: ChunkedVec<(usize, Rc<()>), 3> =
+                        (0..len).map(|i| (i, marker.clone())).collect();
+                    let mut iter = vec.into_iter();
+
+                    for i in 0..from_front {
+                        assert_eq!(iter.next().unwrap().0, i);
+                    }
+                    for i in 0..from_back {
+                        assert_eq!(iter.next_back().unwrap().0, len - 1 - i);
+                    }
+                    assert_eq!(iter.len(), len - from_front - from_back);
+                    assert_eq!(Rc::strong_count(&marker), 1 + iter.len());
+
+                    drop(iter);
+                    assert_eq!(Rc::strong_count(&marker), 1);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_into_iter_fused() {
+        let vec: ChunkedVec<i32, 2> = (0..3).collect();
+        let mut iter = vec.into_iter();
+        assert_eq!(iter.next_back(), Some(2));
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.next_back(), Some(1));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_into_iter_nth_drops_skipped() {
+        use std::rc::Rc;
+
+        let marker = Rc::new(());
+        let vec: ChunkedVec<(usize, Rc<()>), 4> = (0..30).map(|i| (i, marker.clone())).collect();
+        let mut iter = vec.into_iter();
+
+        assert_eq!(iter.nth(9).unwrap().0, 9);
+        assert_eq!(Rc::strong_count(&marker), 1 + 20);
+        assert_eq!(iter.nth_back(5).unwrap().0, 24);
+        assert_eq!(Rc::strong_count(&marker), 1 + 14);
+        assert!(iter.nth(100).is_none());
+        assert_eq!(Rc::strong_count(&marker), 1);
+        assert!(iter.next_back().is_none());
+    }
+
+    #[test]
+    fn test_into_iter_count_last_fold() {
+        let vec: ChunkedVec<String, 3> = (0..10).map(|i| i.to_string()).collect();
+        assert_eq!(vec.into_iter().skip(3).count(), 7);
+
+        let vec: ChunkedVec<String, 3> = (0..10).map(|i| i.to_string()).collect();
+        assert_eq!(vec.into_iter().last().as_deref(), Some("9"));
+
+        let vec: ChunkedVec<String, 3> = (0..10).map(|i| i.to_string()).collect();
+        let mut iter = vec.into_iter();
+        iter.next();
+        iter.next_back();
+        assert_eq!(iter.fold(String::new(), |acc, s| acc + &s), "12345678");
+
+        let vec: ChunkedVec<String, 3> = (0..10).map(|i| i.to_string()).collect();
+        assert_eq!(
+            vec.into_iter().rfold(String::new(), |acc, s| acc + &s),
+            "9876543210"
+        );
+    }
+
+    #[test]
+    fn test_into_iter_fold_panic_drops_rest_once() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        use std::rc::Rc;
+
+        let marker = Rc::new(());
+        for panic_at in 0..10 {
+            let vec: ChunkedVec<Rc<()>, 3> = (0..10).map(|_| marker.clone()).collect();
+            let result = catch_unwind(AssertUnwindSafe(|| {
+                vec.into_iter().fold(0, |i, _item| {
+                    if i == panic_at {
+                        panic!("fold panic");
+                    }
+                    i + 1
+                })
+            }));
+            assert!(result.is_err());
+            assert_eq!(Rc::strong_count(&marker), 1);
+        }
+    }
+
+    #[test]
+    fn test_into_iter_panicking_drop_drops_rest_once() {
+        use std::cell::Cell;
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        struct PanicOnDrop<'a> {
+            id: usize,
+            drops: &'a Cell<usize>,
+        }
+
+        impl Drop for PanicOnDrop<'_> {
+            fn drop(&mut self) {
+                self.drops.set(self.drops.get() + 1);
+                if self.id == 3 {
+                    panic!("drop panic");
+                }
+            }
+        }
+
+        let drops = Cell::new(0);
+        let vec: ChunkedVec<PanicOnDrop, 2> =
+            (0..6).map(|id| PanicOnDrop { id, drops: &drops }).collect();
+        let mut iter = vec.into_iter();
+        drop(iter.next());
+        assert_eq!(drops.get(), 1);
+
+        let result = catch_unwind(AssertUnwindSafe(|| drop(iter)));
+        assert!(result.is_err());
+        // Every element was dropped exactly once, including those after the panic
+        assert_eq!(drops.get(), 6);
+    }
 }
@@ -1,4 +1,6 @@
+use std::iter::FusedIterator;
 use std::mem::MaybeUninit;
+use std::slice;
 
 use likely_stable::unlikely;
 
@@ -8,13 +10,24 @@ use crate::ChunkedVec;
 ///
 /// This struct is created by the [`iter`] method on [`ChunkedVec`].
 /// See its documentation for more.
+///
+/// [`iter`]: ChunkedVec::iter
 pub struct Iter<'a, T, const N: usize> {
     pub(crate) vec: &'a ChunkedVec<T, N>,
     pub(crate) chunk_idx: usize,
     pub(crate) offset: usize,
+    pub(crate) back_chunk_idx: usize,
+    pub(crate) back_offset: usize,
     pub(crate) remaining: usize,
 }
 
+// Safety: the iterator only hands out shared references, like
+// `slice::Iter`.
+unsafe impl<T: Sync, const N: usize> Send for Iter<'_, T, N> {}
+
+// Safety: see above.
+unsafe impl<T: Sync, const N: usize> Sync for Iter<'_, T, N> {}
+
 impl<T, const N: usize> ChunkedVec<T, N> {
     /// Returns an iterator over the elements of the vector.
     ///
@@ -34,16 +47,28 @@ impl<T, const N: usize> ChunkedVec<T, N> {
     /// assert_eq!(sum, 3);
     /// ```
     pub fn iter(&self) -> Iter<'_, T, N> {
-        Iter {
-            vec: self,
-            chunk_idx: 0,
-            offset: 0,
-            remaining: self.len(),
-        }
+        Iter::new(self, 0, self.len)
     }
 }
 
 impl<'a, T, const N: usize> Iter<'a, T, N> {
+    /// Creates an iterator over the logical range `[start, end)` of `vec`.
+    ///
+    /// The range must lie within `vec.len()`.
+    pub(crate) fn new(vec: &'a ChunkedVec<T, N>, start: usize, end: usize) -> Self {
+        debug_assert!(start <= end && end <= vec.len);
+        let (chunk_idx, offset) = vec.chunk_and_offset(start);
+        let (back_chunk_idx, back_offset) = vec.chunk_and_offset(end);
+        Iter {
+            vec,
+            chunk_idx,
+            offset,
+            back_chunk_idx,
+            back_offset,
+            remaining: end - start,
+        }
+    }
+
     /// Advances to the next position.
     #[inline]
     unsafe fn advance_position(&mut self) {
@@ -55,11 +80,89 @@ impl<'a, T, const N: usize> Iter<'a, T, N> {
         self.remaining -= 1;
     }
 
+    /// Moves the back cursor to the previous position.
+    #[inline]
+    unsafe fn retreat_position(&mut self) {
+        if unlikely(self.back_offset == 0) {
+            self.back_chunk_idx -= 1;
+            self.back_offset = N;
+        }
+        self.back_offset -= 1;
+        self.remaining -= 1;
+    }
+
     /// Returns a pointer to the current element.
     #[inline]
     fn current_ptr(&mut self) -> &'a MaybeUninit<T> {
         &self.vec.data[self.chunk_idx][self.offset]
     }
+
+    /// Returns a pointer to the element under the back cursor.
+    #[inline]
+    fn back_ptr(&mut self) -> &'a MaybeUninit<T> {
+        &self.vec.data[self.back_chunk_idx][self.back_offset]
+    }
+
+    /// Moves the front cursor forward by `n` positions in *O*(1).
+    ///
+    /// `n` must not exceed `remaining`.
+    #[inline]
+    fn skip_front(&mut self, n: usize) {
+        let pos = self.offset + n;
+        self.chunk_idx += pos / N;
+        self.offset = pos % N;
+        self.remaining -= n;
+    }
+
+    /// Moves the back cursor backward by `n` positions in *O*(1).
+    ///
+    /// `n` must not exceed `remaining`.
+    #[inline]
+    fn skip_back(&mut self, n: usize) {
+        let pos = self.back_chunk_idx * N + self.back_offset - n;
+        self.back_chunk_idx = pos / N;
+        self.back_offset = pos % N;
+        self.remaining -= n;
+    }
+
+    /// Returns the contiguous run of remaining elements that starts at the
+    /// front cursor and ends at the end of its chunk, and steps over it.
+    ///
+    /// Must only be called while `remaining > 0`.
+    #[inline]
+    fn take_front_run(&mut self) -> &'a [T] {
+        let len = (N - self.offset).min(self.remaining);
+        // Safety: the `len` slots from the front cursor are initialized and
+        // lie within one chunk.
+        let run = unsafe {
+            slice::from_raw_parts(self.vec.get_elem_ptr(self.chunk_idx, self.offset), len)
+        };
+        self.skip_front(len);
+        run
+    }
+
+    /// Returns the contiguous run of remaining elements that ends at the
+    /// back cursor and starts at the beginning of its chunk, and steps over
+    /// it.
+    ///
+    /// Must only be called while `remaining > 0`.
+    #[inline]
+    fn take_back_run(&mut self) -> &'a [T] {
+        if self.back_offset == 0 {
+            self.back_chunk_idx -= 1;
+            self.back_offset = N;
+        }
+        let len = self.back_offset.min(self.remaining);
+        self.skip_back(len);
+        // Safety: the `len` slots from the back cursor are initialized and
+        // lie within one chunk.
+        unsafe {
+            slice::from_raw_parts(
+                self.vec.get_elem_ptr(self.back_chunk_idx, self.back_offset),
+                len,
+            )
+        }
+    }
 }
 
 impl<'a, T, const N: usize> Iterator for Iter<'a, T, N> {
@@ -81,8 +184,80 @@ impl<'a, T, const N: usize> Iterator for Iter<'a, T, N> {
         let remaining = self.remaining;
         (remaining, Some(remaining))
     }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.remaining
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.remaining {
+            self.remaining = 0;
+            return None;
+        }
+        self.skip_front(n);
+        self.next()
+    }
+
+    fn fold<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let mut acc = init;
+        while self.remaining > 0 {
+            acc = self.take_front_run().iter().fold(acc, &mut f);
+        }
+        acc
+    }
+}
+
+impl<'a, T, const N: usize> DoubleEndedIterator for Iter<'a, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if unlikely(self.remaining == 0) {
+            return None;
+        }
+
+        unsafe {
+            self.retreat_position();
+            Some(self.back_ptr().assume_init_ref())
+        }
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.remaining {
+            self.remaining = 0;
+            return None;
+        }
+        self.skip_back(n);
+        self.next_back()
+    }
+
+    fn rfold<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let mut acc = init;
+        while self.remaining > 0 {
+            acc = self.take_back_run().iter().rfold(acc, &mut f);
+        }
+        acc
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for Iter<'_, T, N> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.remaining
+    }
 }
 
+impl<T, const N: usize> FusedIterator for Iter<'_, T, N> {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -100,4 +275,106 @@ mod tests {
         assert_eq!(iter.next(), Some(&3));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn test_iter_rev() {
+        let vec: ChunkedVec<i32, 3> = (0..10).collect();
+        let reversed: Vec<i32> = vec.iter().rev().copied().collect();
+        assert_eq!(reversed, (0..10).rev().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_iter_both_ends_meet() {
+        for len in 0..10 {
+            let vec: ChunkedVec<i32, 3> = (0..len).collect();
+            let mut iter = vec.iter();
+            let mut front = Vec::new();
+            let mut back = Vec::new();
+            loop {
+                assert_eq!(iter.len(), (len as usize) - front.len() - back.len());
+                match iter.next() {
+                    Some(&x) => front.push(x),
+                    None => break,
+                }
+                match iter.next_back() {
+                    Some(&x) => back.push(x),
+                    None => break,
+                }
+            }
+            back.reverse();
+            front.extend(back);
+            assert_eq!(front, (0..len).collect::<Vec<_>>());
+
+            // Fused: stays exhausted from both ends.
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next_back(), None);
+        }
+    }
+
+    #[test]
+    fn test_iter_rposition() {
+        let vec: ChunkedVec<i32, 4> = [1, 2, 3, 2, 5, 2, 7, 8, 9].into_iter().collect();
+        assert_eq!(vec.iter().rposition(|&x| x == 2), Some(5));
+        assert_eq!(vec.iter().rposition(|&x| x == 10), None);
+        assert_eq!(vec.iter().len(), 9);
+    }
+
+    #[test]
+    fn test_iter_nth_and_skip() {
+        let vec: ChunkedVec<usize, 4> = (0..50).collect();
+        let mut iter = vec.iter();
+        assert_eq!(iter.next(), Some(&0));
+        assert_eq!(iter.nth(2), Some(&3));
+        assert_eq!(iter.nth(10), Some(&14));
+        assert_eq!(iter.len(), 35);
+        assert_eq!(iter.nth_back(4), Some(&45));
+        assert_eq!(iter.next_back(), Some(&44));
+        assert_eq!(iter.len(), 29);
+        assert_eq!(iter.nth(28), Some(&43));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        let skipped: Vec<usize> = vec.iter().skip(37).copied().collect();
+        assert_eq!(skipped, (37..50).collect::<Vec<_>>());
+        assert_eq!(vec.iter().nth(50), None);
+        assert_eq!(vec.iter().nth_back(49), Some(&0));
+    }
+
+    #[test]
+    fn test_iter_count_last() {
+        let vec: ChunkedVec<i32, 3> = (0..10).collect();
+        assert_eq!(vec.iter().count(), 10);
+        assert_eq!(vec.iter().skip(4).count(), 6);
+        assert_eq!(vec.iter().last(), Some(&9));
+        assert_eq!(ChunkedVec::<i32>::new().iter().last(), None);
+    }
+
+    #[test]
+    fn test_iter_fold_across_chunks() {
+        for len in 0..20usize {
+            let vec: ChunkedVec<usize, 4> = (0..len).collect();
+            let expected: Vec<usize> = (0..len).collect();
+
+            let mut iter = vec.iter();
+            iter.next();
+            iter.next_back();
+            let folded = iter.fold(Vec::new(), |mut acc, &x| {
+                acc.push(x);
+                acc
+            });
+            let inner = if len >= 2 {
+                &expected[1..len - 1]
+            } else {
+                &[][..]
+            };
+            assert_eq!(folded, inner);
+
+            let rfolded = vec.iter().rfold(Vec::new(), |mut acc, &x| {
+                acc.push(x);
+                acc
+            });
+            assert!(rfolded.iter().eq(expected.iter().rev()));
+            assert_eq!(vec.iter().sum::<usize>(), expected.iter().sum());
+        }
+    }
 }
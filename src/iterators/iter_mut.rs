@@ -1,3 +1,6 @@
+use std::iter::FusedIterator;
+use std::slice;
+
 use likely_stable::unlikely;
 
 use crate::ChunkedVec;
@@ -6,13 +9,25 @@ use crate::ChunkedVec;
 ///
 /// This struct is created by the [`iter_mut`] method on [`ChunkedVec`].
 /// See its documentation for more.
+///
+/// [`iter_mut`]: ChunkedVec::iter_mut
 pub struct IterMut<'a, T, const N: usize> {
     pub(crate) vec: &'a mut ChunkedVec<T, N>,
     pub(crate) chunk_idx: usize,
     pub(crate) offset: usize,
+    pub(crate) back_chunk_idx: usize,
+    pub(crate) back_offset: usize,
     pub(crate) remaining: usize,
 }
 
+// Safety: the iterator hands out mutable references, like
+// `slice::IterMut`.
+unsafe impl<T: Send, const N: usize> Send for IterMut<'_, T, N> {}
+
+// Safety: a shared reference to it only gives shared access to the
+// elements.
+unsafe impl<T: Sync, const N: usize> Sync for IterMut<'_, T, N> {}
+
 impl<T, const N: usize> ChunkedVec<T, N> {
     /// Returns an iterator that allows modifying each element in the vector.
     ///
@@ -33,16 +48,29 @@ impl<T, const N: usize> ChunkedVec<T, N> {
     /// assert_eq!(vec[1], 4);
     /// ```
     pub fn iter_mut(&mut self) -> IterMut<'_, T, N> {
-        IterMut {
-            remaining: self.len(),
-            vec: self,
-            chunk_idx: 0,
-            offset: 0,
-        }
+        let len = self.len;
+        IterMut::new(self, 0, len)
     }
 }
 
 impl<'a, T, const N: usize> IterMut<'a, T, N> {
+    /// Creates an iterator over the logical range `[start, end)` of `vec`.
+    ///
+    /// The range must lie within `vec.len()`.
+    pub(crate) fn new(vec: &'a mut ChunkedVec<T, N>, start: usize, end: usize) -> Self {
+        debug_assert!(start <= end && end <= vec.len);
+        let (chunk_idx, offset) = vec.chunk_and_offset(start);
+        let (back_chunk_idx, back_offset) = vec.chunk_and_offset(end);
+        IterMut {
+            vec,
+            chunk_idx,
+            offset,
+            back_chunk_idx,
+            back_offset,
+            remaining: end - start,
+        }
+    }
+
     /// Advances to the next position.
     #[inline]
     fn advance_position(&mut self) {
@@ -54,11 +82,90 @@ impl<'a, T, const N: usize> IterMut<'a, T, N> {
         self.remaining -= 1;
     }
 
+    /// Moves the back cursor to the previous position.
+    #[inline]
+    fn retreat_position(&mut self) {
+        if unlikely(self.back_offset == 0) {
+            self.back_chunk_idx -= 1;
+            self.back_offset = N;
+        }
+        self.back_offset -= 1;
+        self.remaining -= 1;
+    }
+
     /// Returns a pointer to the current element.
     #[inline]
     fn current_ptr(&mut self) -> *mut T {
         self.vec.data[self.chunk_idx][self.offset].as_mut_ptr()
     }
+
+    /// Returns a pointer to the element under the back cursor.
+    #[inline]
+    fn back_ptr(&mut self) -> *mut T {
+        self.vec.data[self.back_chunk_idx][self.back_offset].as_mut_ptr()
+    }
+
+    /// Moves the front cursor forward by `n` positions in *O*(1).
+    ///
+    /// `n` must not exceed `remaining`.
+    #[inline]
+    fn skip_front(&mut self, n: usize) {
+        let pos = self.offset + n;
+        self.chunk_idx += pos / N;
+        self.offset = pos % N;
+        self.remaining -= n;
+    }
+
+    /// Moves the back cursor backward by `n` positions in *O*(1).
+    ///
+    /// `n` must not exceed `remaining`.
+    #[inline]
+    fn skip_back(&mut self, n: usize) {
+        let pos = self.back_chunk_idx * N + self.back_offset - n;
+        self.back_chunk_idx = pos / N;
+        self.back_offset = pos % N;
+        self.remaining -= n;
+    }
+
+    /// Returns the contiguous run of remaining elements that starts at the
+    /// front cursor and ends at the end of its chunk, and steps over it.
+    ///
+    /// Must only be called while `remaining > 0`.
+    #[inline]
+    fn take_front_run(&mut self) -> &'a mut [T] {
+        let len = (N - self.offset).min(self.remaining);
+        // Safety: the `len` slots from the front cursor are initialized, lie
+        // within one chunk, and are never handed out again once skipped.
+        let run = unsafe {
+            slice::from_raw_parts_mut(self.vec.get_elem_mut_ptr(self.chunk_idx, self.offset), len)
+        };
+        self.skip_front(len);
+        run
+    }
+
+    /// Returns the contiguous run of remaining elements that ends at the
+    /// back cursor and starts at the beginning of its chunk, and steps over
+    /// it.
+    ///
+    /// Must only be called while `remaining > 0`.
+    #[inline]
+    fn take_back_run(&mut self) -> &'a mut [T] {
+        if self.back_offset == 0 {
+            self.back_chunk_idx -= 1;
+            self.back_offset = N;
+        }
+        let len = self.back_offset.min(self.remaining);
+        self.skip_back(len);
+        // Safety: the `len` slots from the back cursor are initialized, lie
+        // within one chunk, and are never handed out again once skipped.
+        unsafe {
+            slice::from_raw_parts_mut(
+                self.vec
+                    .get_elem_mut_ptr(self.back_chunk_idx, self.back_offset),
+                len,
+            )
+        }
+    }
 }
 
 impl<'a, T, const N: usize> Iterator for IterMut<'a, T, N> {
@@ -83,8 +190,83 @@ impl<'a, T, const N: usize> Iterator for IterMut<'a, T, N> {
         let remaining = self.remaining;
         (remaining, Some(remaining))
     }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.remaining
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.remaining {
+            self.remaining = 0;
+            return None;
+        }
+        self.skip_front(n);
+        self.next()
+    }
+
+    fn fold<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let mut acc = init;
+        while self.remaining > 0 {
+            acc = self.take_front_run().iter_mut().fold(acc, &mut f);
+        }
+        acc
+    }
+}
+
+impl<'a, T, const N: usize> DoubleEndedIterator for IterMut<'a, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if unlikely(self.remaining == 0) {
+            return None;
+        }
+
+        unsafe {
+            // The front and back cursors never hand out the same slot: both
+            // are bounded by `remaining`.
+            self.retreat_position();
+            let ptr = self.back_ptr();
+            Some(&mut *ptr)
+        }
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.remaining {
+            self.remaining = 0;
+            return None;
+        }
+        self.skip_back(n);
+        self.next_back()
+    }
+
+    fn rfold<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let mut acc = init;
+        while self.remaining > 0 {
+            acc = self.take_back_run().iter_mut().rfold(acc, &mut f);
+        }
+        acc
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for IterMut<'_, T, N> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.remaining
+    }
 }
 
+impl<T, const N: usize> FusedIterator for IterMut<'_, T, N> {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -105,4 +287,57 @@ mod tests {
         assert_eq!(iter.next(), None);
         assert_eq!(vec[2], 4);
     }
+
+    #[test]
+    fn test_iter_mut_rev() {
+        let mut vec: ChunkedVec<i32, 3> = (0..10).collect();
+        for (i, elem) in vec.iter_mut().rev().enumerate() {
+            *elem += i as i32 * 100;
+        }
+        assert_eq!(vec[9], 9);
+        assert_eq!(vec[0], 900);
+    }
+
+    #[test]
+    fn test_iter_mut_both_ends_meet() {
+        let mut vec: ChunkedVec<i32, 4> = (0..9).collect();
+        let mut iter = vec.iter_mut();
+        assert_eq!(iter.len(), 9);
+        *iter.next().unwrap() = -1;
+        *iter.next_back().unwrap() = -2;
+        *iter.next_back().unwrap() = -3;
+        assert_eq!(iter.len(), 6);
+        let rest: Vec<i32> = iter.map(|x| *x).collect();
+        assert_eq!(rest, [1, 2, 3, 4, 5, 6]);
+        assert_eq!(vec, [-1, 1, 2, 3, 4, 5, 6, -3, -2]);
+    }
+
+    #[test]
+    fn test_iter_mut_nth() {
+        let mut vec: ChunkedVec<i32, 4> = (0..30).collect();
+        let mut iter = vec.iter_mut();
+        *iter.nth(9).unwrap() = -9;
+        *iter.nth_back(9).unwrap() = -20;
+        assert_eq!(iter.len(), 10);
+        assert_eq!(iter.nth(10), None);
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(vec[9], -9);
+        assert_eq!(vec[20], -20);
+        assert_eq!(vec.iter_mut().count(), 30);
+        assert_eq!(vec.iter_mut().last(), Some(&mut 29));
+    }
+
+    #[test]
+    fn test_iter_mut_fold_and_for_each() {
+        let mut vec: ChunkedVec<i32, 3> = (0..11).collect();
+        vec.iter_mut().skip(1).for_each(|x| *x *= 10);
+        assert_eq!(vec, [0, 10, 20, 30, 40, 50, 60, 70, 80, 90, 100]);
+
+        let order = vec.iter_mut().rfold(Vec::new(), |mut acc, x| {
+            *x += 1;
+            acc.push(*x);
+            acc
+        });
+        assert_eq!(order, [101, 91, 81, 71, 61, 51, 41, 31, 21, 11, 1]);
+    }
 }
@@ -1,3 +1,11 @@
+mod chunks;
+mod drain;
 mod into_iter;
 mod iter;
 mod iter_mut;
+
+pub use chunks::{Chunks, ChunksMut};
+pub use drain::Drain;
+pub use into_iter::IntoIter;
+pub use iter::Iter;
+pub use iter_mut::IterMut;
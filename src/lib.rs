@@ -8,8 +8,11 @@
 //! # Feature flags
 //! - `serde` — enables [`serde::Serialize`](https://docs.rs/serde/latest/serde/trait.Serialize.html)
 //!   and [`serde::Deserialize`](https://docs.rs/serde/latest/serde/trait.Deserialize.html) for
-//!   `ChunkedVec<T, N>`, serialized as a plain sequence interoperable with `Vec<T>`.
-//!   Disabled by default.
+//!   `ChunkedVec<T, N>` and `PersistentChunkedVec<T, N>`, serialized as a plain sequence
+//!   interoperable with `Vec<T>`. Disabled by default.
+//! - `rayon` — enables parallel iteration (`par_iter`, `par_iter_mut`, `into_par_iter`),
+//!   `ParallelExtend` and `FromParallelIterator`, and `par_sort_unstable*`, built on
+//!   [rayon](https://docs.rs/rayon). Disabled by default.
 //!
 //! # Example
 //! ```
@@ -25,15 +28,37 @@
 const DEFAULT_CHUNK_SIZE: usize = 64;
 
 mod chunked_vec;
+mod concurrent;
 mod constructors;
+mod debug;
 mod drop;
+mod fallible;
 mod index;
 pub(crate) mod internal;
 mod iterators;
 mod operations;
+mod persistent;
+mod pinned;
+mod pool;
+#[cfg(feature = "rayon")]
+mod rayon;
+mod reorder;
+mod retain;
+mod search;
 #[cfg(feature = "serde")]
 mod serde;
+mod slice;
 mod sort;
+mod stable_sort;
 mod traits;
 
+#[cfg(feature = "rayon")]
+pub use crate::rayon::{IntoParIter, ParIter, ParIterMut};
 pub use chunked_vec::*;
+pub use concurrent::ConcurrentChunkedVec;
+pub use debug::DebugLayout;
+pub use fallible::TryPushError;
+pub use iterators::{Chunks, ChunksMut, Drain, IntoIter, Iter, IterMut};
+pub use persistent::{PersistentChunkedVec, PersistentIter};
+pub use pool::{ChunkPool, PoolStats};
+pub use slice::{ChunkedSlice, ChunkedSliceMut};
@@ -1,6 +1,5 @@
 use crate::ChunkedVec;
-use std::array::from_fn;
-use std::mem::MaybeUninit;
+use std::collections::TryReserveError;
 use std::ptr;
 
 /// Implementation of basic operations for ChunkedVec.
@@ -17,7 +16,58 @@ impl<T, const N: usize> ChunkedVec<T, N> {
         }
     }
 
-    unsafe fn drop_range(&mut self, start: usize, end: usize) {
+    /// Returns the number of chunks needed to hold `additional` more
+    /// elements.
+    ///
+    /// # Panics
+    /// Panics if the required length overflows `usize`.
+    #[inline]
+    pub(crate) fn required_chunks(&self, additional: usize) -> usize {
+        self.len
+            .checked_add(additional)
+            .expect("capacity overflow")
+            .div_ceil(N)
+    }
+
+    /// Allocates empty chunks until `additional` more elements fit in
+    /// allocated chunks.
+    ///
+    /// # Panics
+    /// Panics if the required length overflows `usize`.
+    pub(crate) fn allocate_chunks_for(&mut self, additional: usize) {
+        let required_chunks = self.required_chunks(additional);
+        if required_chunks > self.data.len() {
+            self.data
+                .resize_with(required_chunks, Self::create_empty_chunk);
+        }
+    }
+
+    /// Drops the elements in the logical range `[start, end)` in place, one
+    /// chunk run at a time.
+    ///
+    /// If dropping an element panics, the remaining elements of the range
+    /// are still dropped in place, matching `ptr::drop_in_place` on a slice.
+    /// Pinned elements rely on this: their memory is never released without
+    /// running their destructor.
+    ///
+    /// # Safety
+    /// Every slot in the range must be initialized, and must be treated as
+    /// uninitialized afterwards.
+    pub(crate) unsafe fn drop_range(&mut self, start: usize, end: usize) {
+        /// Drops what is left of the range if dropping a run unwinds.
+        struct DropRest<'a, T, const N: usize> {
+            vec: &'a mut ChunkedVec<T, N>,
+            start: usize,
+            end: usize,
+        }
+
+        impl<T, const N: usize> Drop for DropRest<'_, T, N> {
+            fn drop(&mut self) {
+                // Safety: the rest of the range is still initialized.
+                unsafe { self.vec.drop_range(self.start, self.end) };
+            }
+        }
+
         if !std::mem::needs_drop::<T>() || start >= end {
             return;
         }
@@ -28,18 +78,89 @@ impl<T, const N: usize> ChunkedVec<T, N> {
             let offset = index % N;
             let to_drop = (end - index).min(N - offset);
             let chunk_ptr = self.data[chunk_idx].as_mut_ptr();
+            let run = ptr::slice_from_raw_parts_mut(chunk_ptr.add(offset).cast::<T>(), to_drop);
 
+            let rest = DropRest {
+                vec: self,
+                start: index + to_drop,
+                end,
+            };
             unsafe {
-                ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
-                    chunk_ptr.add(offset).cast::<T>(),
-                    to_drop,
-                ));
+                ptr::drop_in_place(run);
             }
+            std::mem::forget(rest);
 
             index += to_drop;
         }
     }
 
+    /// Moves `count` elements starting at logical index `src` down to logical
+    /// index `dst`, one chunk run at a time.
+    ///
+    /// # Safety
+    /// `dst` must not exceed `src`, and every slot in both ranges must lie in
+    /// an allocated chunk. The source slots are left logically uninitialized
+    /// unless they are overwritten as part of the destination range.
+    pub(crate) unsafe fn move_range_down(&mut self, mut src: usize, mut dst: usize, count: usize) {
+        debug_assert!(dst <= src);
+        if dst == src {
+            return;
+        }
+
+        let end = src + count;
+        while src < end {
+            let (src_chunk, src_off) = self.chunk_and_offset(src);
+            let (dst_chunk, dst_off) = self.chunk_and_offset(dst);
+            let run = (end - src).min(N - src_off).min(N - dst_off);
+
+            // Copying front to back never overwrites unread source slots
+            // because `dst < src`; `ptr::copy` handles same-chunk overlap.
+            if src_chunk == dst_chunk {
+                let base = self.get_chunk_mut_ptr(src_chunk);
+                ptr::copy(base.add(src_off), base.add(dst_off), run);
+            } else {
+                let src_ptr = self.get_elem_ptr(src_chunk, src_off);
+                let dst_ptr = self.get_elem_mut_ptr(dst_chunk, dst_off);
+                ptr::copy_nonoverlapping(src_ptr, dst_ptr, run);
+            }
+
+            src += run;
+            dst += run;
+        }
+    }
+
+    /// Bitwise-copies `count` elements starting at logical index `start`
+    /// into `dst`, one chunk run at a time.
+    ///
+    /// # Safety
+    /// `[start, start + count)` must be within `self.len` and `dst` valid for
+    /// `count` writes that do not overlap the vector.
+    pub(crate) unsafe fn copy_range_out(&mut self, start: usize, count: usize, dst: *mut T) {
+        let mut done = 0;
+        while done < count {
+            let (chunk_idx, offset) = self.chunk_and_offset(start + done);
+            let run = (N - offset).min(count - done);
+            ptr::copy_nonoverlapping(self.get_elem_ptr(chunk_idx, offset), dst.add(done), run);
+            done += run;
+        }
+    }
+
+    /// Bitwise-copies `count` elements from `src` into the slots starting at
+    /// logical index `start`, one chunk run at a time.
+    ///
+    /// # Safety
+    /// `[start, start + count)` must lie within allocated chunks and `src`
+    /// be valid for `count` reads that do not overlap the vector.
+    pub(crate) unsafe fn copy_range_in(&mut self, src: *const T, start: usize, count: usize) {
+        let mut done = 0;
+        while done < count {
+            let (chunk_idx, offset) = self.chunk_and_offset(start + done);
+            let run = (N - offset).min(count - done);
+            ptr::copy_nonoverlapping(src.add(done), self.get_elem_mut_ptr(chunk_idx, offset), run);
+            done += run;
+        }
+    }
+
     /// Appends an element to the back of the vector.
     ///
     /// If the current chunk is full, a new chunk will be allocated to store the element.
@@ -69,6 +190,111 @@ impl<T, const N: usize> ChunkedVec<T, N> {
         self.len += 1;
     }
 
+    /// Appends an element to the back of the vector and returns a mutable
+    /// reference to it.
+    ///
+    /// Like [`push`](ChunkedVec::push), this never moves the elements
+    /// already in the vector; see [stable element
+    /// addresses](ChunkedVec#stable-element-addresses).
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkedVec, ChunkedVecSized};
+    /// let mut vec: ChunkedVec<Vec<i32>, 2> = ChunkedVecSized::new();
+    /// let inner = vec.push_get(Vec::new());
+    /// inner.push(1);
+    /// assert_eq!(vec[0], [1]);
+    /// ```
+    pub fn push_get(&mut self, value: T) -> &mut T {
+        self.push(value);
+        let (chunk_idx, offset) = self.chunk_and_offset(self.len - 1);
+        // Safety: the slot was just initialized by `push`.
+        unsafe { &mut *self.get_elem_mut_ptr(chunk_idx, offset) }
+    }
+
+    /// Removes the last element from the vector and returns it, or [`None`]
+    /// if it is empty.
+    ///
+    /// Like [`Vec::pop`], this never releases allocated chunks.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let mut vec = chunked_vec![1, 2, 3];
+    /// assert_eq!(vec.pop(), Some(3));
+    /// assert_eq!(vec, [1, 2]);
+    /// ```
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.len -= 1;
+        let (chunk_idx, offset) = self.chunk_and_offset(self.len);
+        // Safety: the slot at the old last index is initialized, and is no
+        // longer counted by `len`, so it will not be dropped again.
+        unsafe { Some(ptr::read(self.get_elem_ptr(chunk_idx, offset))) }
+    }
+
+    /// Inserts an element at position `index` within the vector, shifting all
+    /// elements after it to the right.
+    ///
+    /// Elements are shifted across chunk boundaries, mirroring [`remove`]. If
+    /// the last allocated chunk is full, a new trailing chunk is allocated.
+    ///
+    /// [`remove`]: ChunkedVec::remove
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let mut vec = chunked_vec![1, 2, 3];
+    /// vec.insert(1, 4);
+    /// assert_eq!(vec, [1, 4, 2, 3]);
+    /// vec.insert(4, 5);
+    /// assert_eq!(vec, [1, 4, 2, 3, 5]);
+    /// ```
+    pub fn insert(&mut self, index: usize, element: T) {
+        let len = self.len;
+        if index > len {
+            panic!("insertion index (is {index}) should be <= len (is {len})");
+        }
+
+        let (chunk_idx, offset) = self.chunk_and_offset(index);
+        let (last_chunk_idx, last_offset) = self.chunk_and_offset(len);
+        if last_chunk_idx >= self.data.len() {
+            self.data.push(Self::create_empty_chunk());
+        }
+
+        unsafe {
+            // Shift elements between chunks, from the back: each chunk moves
+            // right by one and receives the last element of its predecessor.
+            let mut end = last_offset;
+            for i in (chunk_idx + 1..=last_chunk_idx).rev() {
+                let current_chunk_ptr = self.get_chunk_mut_ptr(i);
+                ptr::copy(current_chunk_ptr, current_chunk_ptr.add(1), end);
+
+                let prev_chunk_ptr = self.get_chunk_mut_ptr(i - 1);
+                ptr::copy_nonoverlapping(prev_chunk_ptr.add(N - 1), current_chunk_ptr, 1);
+                end = N - 1;
+            }
+
+            // Shift elements within the target chunk and write the new one
+            let first_chunk_ptr = self.get_chunk_mut_ptr(chunk_idx);
+            ptr::copy(
+                first_chunk_ptr.add(offset),
+                first_chunk_ptr.add(offset + 1),
+                end - offset,
+            );
+            ptr::write(first_chunk_ptr.add(offset), element);
+        }
+
+        self.len += 1;
+    }
+
     /// Resizes the `ChunkedVec` in-place so that `len` is equal to `new_len`.
     ///
     /// If `new_len` is greater than `len`, the `Vec` is extended by the
@@ -86,6 +312,9 @@ impl<T, const N: usize> ChunkedVec<T, N> {
     ///
     /// Panics if the new capacity exceeds `isize::MAX` _bytes_.
     ///
+    /// If `clone` panics, the vector keeps the elements cloned so far, so it
+    /// stays valid after the panic is caught.
+    ///
     /// # Examples
     ///
     /// ```
@@ -102,25 +331,215 @@ impl<T, const N: usize> ChunkedVec<T, N> {
         let old_len = self.len;
 
         if new_len > old_len {
-            let required_chunks = (new_len + N - 1) / N;
+            let required_chunks = new_len.div_ceil(N);
             if required_chunks > self.data.len() {
-                self.data.resize_with(required_chunks, || {
-                    let arr: [MaybeUninit<T>; N] = from_fn(|_| MaybeUninit::uninit());
-                    Box::new(arr)
-                });
+                self.data
+                    .resize_with(required_chunks, Self::create_empty_chunk);
             }
 
             for i in old_len..new_len {
                 let chunk_idx = i / N;
                 let offset = i % N;
                 self.data[chunk_idx][offset].write(value.clone());
+                // Counted right away, so that if a later `clone` panics, the
+                // clones written so far are dropped with the vector rather
+                // than leaked.
+                self.len += 1;
             }
         } else if new_len < old_len {
             self.truncate(new_len);
+        }
+    }
+
+    /// Moves all the elements of `other` into `self`, leaving `other` empty.
+    ///
+    /// When `self.len()` is a multiple of `N`, the initialized chunks of
+    /// `other` are moved over as whole boxes and no element is copied;
+    /// chunks retained by `self` beyond its length stay after them. Otherwise
+    /// the elements are copied one chunk run at a time. Either way, `other`
+    /// keeps any chunks it does not hand over.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkedVec, ChunkedVecSized};
+    /// let mut vec: ChunkedVec<i32, 2> = ChunkedVecSized::new();
+    /// vec.extend([1, 2]);
+    /// let mut other: ChunkedVec<i32, 2> = ChunkedVecSized::new();
+    /// other.extend([3, 4, 5]);
+    ///
+    /// let first_chunk = other.chunk(0).unwrap().as_ptr();
+    /// vec.append(&mut other);
+    /// assert_eq!(vec, [1, 2, 3, 4, 5]);
+    /// assert!(other.is_empty());
+    /// // The chunk was moved, not copied
+    /// assert_eq!(vec.chunk(1).unwrap().as_ptr(), first_chunk);
+    /// ```
+    pub fn append(&mut self, other: &mut Self) {
+        let count = other.len;
+        if count == 0 {
             return;
         }
 
-        self.len = new_len;
+        let (at, offset) = self.chunk_and_offset(self.len);
+        if offset == 0 {
+            other.len = 0;
+            self.data
+                .splice(at..at, other.data.drain(..count.div_ceil(N)));
+        } else {
+            self.allocate_chunks_for(count);
+            other.len = 0;
+            let mut dst = self.len;
+            let mut src = 0;
+            while src < count {
+                let (chunk_idx, offset) = other.chunk_and_offset(src);
+                let run = (count - src).min(N - offset);
+                // Safety: the source run is initialized and no longer counted
+                // by `other`, and the destination slots are allocated and
+                // unused.
+                unsafe { self.copy_range_in(other.get_elem_ptr(chunk_idx, offset), dst, run) };
+                src += run;
+                dst += run;
+            }
+        }
+        self.len += count;
+    }
+
+    /// Moves all the elements of a `Vec` into `self`, leaving it empty.
+    ///
+    /// The elements are copied with one `ptr::copy_nonoverlapping` per
+    /// chunk run. Like [`Vec::append`], `other` keeps its capacity.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkedVec, ChunkedVecSized};
+    /// let mut vec: ChunkedVec<String, 2> = ChunkedVecSized::new();
+    /// vec.push("a".to_string());
+    /// let mut other = vec!["b".to_string(), "c".to_string()];
+    ///
+    /// vec.append_vec(&mut other);
+    /// assert_eq!(vec, ["a", "b", "c"]);
+    /// assert!(other.is_empty());
+    /// ```
+    pub fn append_vec(&mut self, other: &mut Vec<T>) {
+        let count = other.len();
+        self.allocate_chunks_for(count);
+        // Safety: the elements are moved out of `other` before they are
+        // copied, and the destination slots are allocated and unused.
+        unsafe {
+            other.set_len(0);
+            self.copy_range_in(other.as_ptr(), self.len, count);
+        }
+        self.len += count;
+    }
+
+    /// Splits the vector into two at the given index.
+    ///
+    /// Returns a newly allocated vector containing the elements in the range
+    /// `[at, len)`. After the call, `self` is left containing the elements
+    /// `[0, at)`.
+    ///
+    /// When `at` is a multiple of `N`, the chunks holding the tail are moved
+    /// into the returned vector without copying any element. Otherwise the
+    /// tail is copied into new chunks and `self` retains its own chunks, as
+    /// with [`truncate`](ChunkedVec::truncate).
+    ///
+    /// # Panics
+    /// Panics if `at > len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkedVec, ChunkedVecSized};
+    /// let mut vec: ChunkedVec<char, 2> = ChunkedVecSized::new();
+    /// vec.extend(['a', 'b', 'c', 'd', 'e']);
+    ///
+    /// let tail = vec.split_off(2);
+    /// assert_eq!(vec, ['a', 'b']);
+    /// assert_eq!(tail, ['c', 'd', 'e']);
+    /// ```
+    #[must_use = "use `.truncate()` if you don't need the other half"]
+    pub fn split_off(&mut self, at: usize) -> Self {
+        if at > self.len {
+            panic!(
+                "`at` split index (is {at}) should be <= len (is {})",
+                self.len
+            );
+        }
+
+        let count = self.len - at;
+        let (first, offset) = self.chunk_and_offset(at);
+        if offset == 0 {
+            let data: Vec<_> = self.data.drain(first..first + count.div_ceil(N)).collect();
+            self.len = at;
+            return ChunkedVec { data, len: count };
+        }
+
+        let chunks = count.div_ceil(N);
+        let mut data = Vec::with_capacity(chunks);
+        data.resize_with(chunks, Self::create_empty_chunk);
+        let mut tail = ChunkedVec { data, len: 0 };
+
+        self.len = at;
+        let mut src = at;
+        let mut dst = 0;
+        while dst < count {
+            let (chunk_idx, offset) = self.chunk_and_offset(src);
+            let run = (count - dst).min(N - offset);
+            // Safety: the source run is initialized and no longer counted by
+            // `self`, and the destination slots are allocated and unused.
+            unsafe { tail.copy_range_in(self.get_elem_ptr(chunk_idx, offset), dst, run) };
+            src += run;
+            dst += run;
+        }
+        tail.len = count;
+        tail
+    }
+
+    /// Clones and appends all elements in a slice to the vector.
+    ///
+    /// The partially filled last chunk and any retained chunks are filled
+    /// first. Every further run of `N` elements is cloned in bulk into a
+    /// newly allocated chunk.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkedVec, ChunkedVecSized};
+    /// let mut vec: ChunkedVec<i32, 4> = ChunkedVecSized::new();
+    /// vec.push(1);
+    /// vec.extend_from_slice(&[2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    /// assert_eq!(vec, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    /// ```
+    pub fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Clone,
+    {
+        let required_chunks = (self.len + other.len()).div_ceil(N);
+        self.data
+            .reserve(required_chunks.saturating_sub(self.data.len()));
+
+        let mut rest = other;
+        while !rest.is_empty() {
+            if self.len == self.allocated_capacity() {
+                if rest.len() >= N {
+                    let (head, tail) = rest.split_at(N);
+                    self.data.push(Self::create_chunk_from_slice(head));
+                    self.len += N;
+                    rest = tail;
+                    continue;
+                }
+                self.data.push(Self::create_empty_chunk());
+            }
+
+            let (chunk_idx, offset) = self.chunk_and_offset(self.len);
+            let (head, tail) = rest.split_at(rest.len().min(N - offset));
+            for (i, item) in head.iter().enumerate() {
+                let value = item.clone();
+                // Safety: the slot is allocated and unused. `len` is bumped
+                // after every write so a panicking clone leaks nothing.
+                unsafe { self.get_elem_mut_ptr(chunk_idx, offset + i).write(value) };
+                self.len += 1;
+            }
+            rest = tail;
+        }
     }
 
     /// Shortens the vector, keeping the first `len` elements and dropping the
@@ -196,9 +615,129 @@ impl<T, const N: usize> ChunkedVec<T, N> {
     /// assert_eq!(vec.allocated_capacity(), 0);
     /// ```
     pub fn shrink_to_fit(&mut self) {
-        let required_chunks = Self::chunk_count_for_len(self.len);
+        self.shrink_to(0);
+    }
+
+    /// Shrinks the allocated storage with a lower bound.
+    ///
+    /// Trailing chunks that are not needed to hold `max(len, min_capacity)`
+    /// elements are freed, and the chunk table is shrunk to match. The
+    /// capacity will remain at least as large as both the length and the
+    /// supplied value.
+    ///
+    /// If the current capacity is less than the lower limit, this is a no-op.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkedVec, ChunkedVecSized};
+    ///
+    /// let mut vec: ChunkedVec<i32, 4> = ChunkedVecSized::new();
+    /// vec.extend(0..20);
+    /// vec.truncate(3);
+    ///
+    /// vec.shrink_to(10);
+    /// assert_eq!(vec.allocated_capacity(), 12);
+    /// vec.shrink_to(0);
+    /// assert_eq!(vec.allocated_capacity(), 4);
+    /// ```
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        let required_chunks = Self::chunk_count_for_len(self.len.max(min_capacity));
         self.data.truncate(required_chunks);
-        self.data.shrink_to_fit();
+        self.data.shrink_to(required_chunks);
+    }
+
+    /// Reserves storage for at least `additional` more elements.
+    ///
+    /// Unlike [`with_capacity`](crate::ChunkedVecSized::with_capacity), which
+    /// only reserves slots in the chunk table, this allocates the chunks
+    /// themselves, so the next `additional` elements are stored without
+    /// allocating. The chunk table may reserve more slots to avoid frequent
+    /// reallocations. Does nothing if the allocated chunks already suffice.
+    ///
+    /// # Panics
+    /// Panics if the required length overflows `usize`.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkedVec, ChunkedVecSized};
+    ///
+    /// let mut vec: ChunkedVec<i32, 4> = ChunkedVecSized::new();
+    /// vec.push(1);
+    /// vec.reserve(10);
+    /// assert_eq!(vec.allocated_capacity(), 12);
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        let required_chunks = self.required_chunks(additional);
+        self.data
+            .reserve(required_chunks.saturating_sub(self.data.len()));
+        self.allocate_chunks_for(additional);
+    }
+
+    /// Reserves storage for at least `additional` more elements, without
+    /// deliberately over-allocating the chunk table.
+    ///
+    /// Element storage is always allocated in whole chunks, exactly as with
+    /// [`reserve`](ChunkedVec::reserve).
+    ///
+    /// # Panics
+    /// Panics if the required length overflows `usize`.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkedVec, ChunkedVecSized};
+    ///
+    /// let mut vec: ChunkedVec<i32, 4> = ChunkedVecSized::new();
+    /// vec.reserve_exact(10);
+    /// assert_eq!(vec.allocated_capacity(), 12);
+    /// assert_eq!(vec.capacity(), 12);
+    /// ```
+    pub fn reserve_exact(&mut self, additional: usize) {
+        let required_chunks = self.required_chunks(additional);
+        self.data
+            .reserve_exact(required_chunks.saturating_sub(self.data.len()));
+        self.allocate_chunks_for(additional);
+    }
+
+    /// Tries to reserve storage for at least `additional` more elements.
+    ///
+    /// Behaves like [`reserve`](ChunkedVec::reserve), but returns an error
+    /// instead of panicking or aborting when the length overflows or an
+    /// allocation fails. On error, the vector is left unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkedVec, ChunkedVecSized};
+    ///
+    /// let mut vec: ChunkedVec<u64, 4> = ChunkedVecSized::new();
+    /// vec.try_reserve(10).expect("allocation failed");
+    /// assert_eq!(vec.allocated_capacity(), 12);
+    ///
+    /// assert!(vec.try_reserve(usize::MAX).is_err());
+    /// assert_eq!(vec.allocated_capacity(), 12);
+    /// ```
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let Some(required) = self.len.checked_add(additional) else {
+            // `TryReserveError` cannot be built directly, so let the chunk
+            // table report the overflow.
+            return self.data.try_reserve(usize::MAX);
+        };
+        let old_chunks = self.data.len();
+        let new_chunks = required.div_ceil(N).saturating_sub(old_chunks);
+        if new_chunks == 0 {
+            return Ok(());
+        }
+
+        self.data.try_reserve(new_chunks)?;
+        for _ in 0..new_chunks {
+            match Self::try_create_empty_chunk() {
+                Ok(chunk) => self.data.push(chunk),
+                Err(err) => {
+                    self.data.truncate(old_chunks);
+                    return Err(err);
+                }
+            }
+        }
+        Ok(())
     }
 
     pub fn remove(&mut self, index: usize) -> T {
@@ -211,6 +750,11 @@ impl<T, const N: usize> ChunkedVec<T, N> {
 
         let (current_chunk_idx, offset) = self.chunk_and_offset(index);
 
+        // Safety: between reading the element out and decrementing `len`,
+        // the element's slot is duplicated and then overwritten by bitwise
+        // copies. No caller code runs in between, so nothing can panic and
+        // observe the vector in that state; `remove` needs no guard to be
+        // unwind safe.
         unsafe {
             // Read the element to be removed
             let ret = ptr::read(self.get_elem_ptr(current_chunk_idx, offset));
@@ -463,6 +1007,54 @@ mod tests {
         assert_eq!(vec.allocated_capacity(), 6);
     }
 
+    #[test]
+    fn test_resize_panicking_clone_keeps_clones() {
+        use std::cell::Cell;
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        struct Bomb<'a> {
+            clones: &'a Cell<usize>,
+            drops: &'a Cell<usize>,
+        }
+
+        impl Clone for Bomb<'_> {
+            fn clone(&self) -> Self {
+                if self.clones.get() == 4 {
+                    panic!("clone failed");
+                }
+                self.clones.set(self.clones.get() + 1);
+                Bomb {
+                    clones: self.clones,
+                    drops: self.drops,
+                }
+            }
+        }
+
+        impl Drop for Bomb<'_> {
+            fn drop(&mut self) {
+                self.drops.set(self.drops.get() + 1);
+            }
+        }
+
+        let (clones, drops) = (Cell::new(0), Cell::new(0));
+        let mut vec: ChunkedVec<Bomb, 3> = ChunkedVecSized::new();
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            vec.resize(
+                10,
+                Bomb {
+                    clones: &clones,
+                    drops: &drops,
+                },
+            );
+        }));
+        assert!(result.is_err());
+        // The four clones made before the panic are in the vector
+        assert_eq!(vec.len(), 4);
+        assert_eq!(drops.get(), 1);
+        drop(vec);
+        assert_eq!(drops.get(), 5);
+    }
+
     #[test]
     fn test_truncate_retains_allocated_chunks() {
         let mut vec: ChunkedVec<i32, 3> = ChunkedVecSized::new();
@@ -830,4 +1422,394 @@ mod tests {
         let mut vec: ChunkedVec<i32, 3> = ChunkedVecSized::new();
         vec.swap_remove(0); // This should panic
     }
+
+    #[test]
+    fn test_pop() {
+        let mut vec: ChunkedVec<i32, 2> = ChunkedVecSized::new();
+        assert_eq!(vec.pop(), None);
+
+        vec.extend(1..=5);
+        assert_eq!(vec.pop(), Some(5));
+        assert_eq!(vec.pop(), Some(4));
+        assert_eq!(vec.len(), 3);
+        assert_eq!(vec.allocated_capacity(), 6); // chunks are retained
+
+        vec.push(6);
+        assert_eq!(vec, [1, 2, 3, 6]);
+    }
+
+    #[test]
+    fn test_pop_with_drop_types() {
+        use std::rc::Rc;
+
+        let mut vec: ChunkedVec<Rc<i32>, 2> = ChunkedVecSized::new();
+        let val = Rc::new(1);
+        for _ in 0..3 {
+            vec.push(val.clone());
+        }
+
+        let popped = vec.pop().unwrap();
+        assert_eq!(Rc::strong_count(&val), 4);
+        drop(popped);
+        assert_eq!(Rc::strong_count(&val), 3);
+        drop(vec);
+        assert_eq!(Rc::strong_count(&val), 1);
+    }
+
+    #[test]
+    fn test_insert_matches_vec() {
+        for len in 0..10 {
+            for index in 0..=len {
+                let mut vec: ChunkedVec<i32, 3> = ChunkedVecSized::new();
+                vec.extend(0..len as i32);
+                let mut expected: Vec<i32> = (0..len as i32).collect();
+
+                vec.insert(index, -1);
+                expected.insert(index, -1);
+
+                assert_eq!(vec.len(), len + 1);
+                assert!(vec.iter().eq(expected.iter()), "len {len}, index {index}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_insert_allocates_trailing_chunk() {
+        let mut vec: ChunkedVec<i32, 2> = ChunkedVecSized::new();
+        vec.extend([1, 2, 3, 4]);
+        assert_eq!(vec.allocated_capacity(), 4);
+
+        vec.insert(0, 0);
+        assert_eq!(vec.allocated_capacity(), 6);
+        assert_eq!(vec, [0, 1, 2, 3, 4]);
+
+        // Reuses a retained chunk instead of allocating
+        vec.truncate(2);
+        vec.insert(1, 9);
+        vec.insert(0, 8);
+        vec.insert(4, 7);
+        assert_eq!(vec.allocated_capacity(), 6);
+        assert_eq!(vec, [8, 0, 9, 1, 7]);
+    }
+
+    #[test]
+    fn test_insert_with_drop_types() {
+        use std::rc::Rc;
+
+        let val = Rc::new(0);
+        {
+            let mut vec: ChunkedVec<Rc<i32>, 3> = ChunkedVecSized::new();
+            for i in 0..7 {
+                vec.insert(i / 2, val.clone());
+            }
+            assert_eq!(Rc::strong_count(&val), 8);
+        }
+        assert_eq!(Rc::strong_count(&val), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "insertion index (is 4) should be <= len (is 3)")]
+    fn test_insert_out_of_bounds() {
+        let mut vec: ChunkedVec<i32, 3> = ChunkedVecSized::new();
+        vec.extend([1, 2, 3]);
+        vec.insert(4, 0);
+    }
+
+    fn strings<const N: usize>(range: std::ops::Range<usize>) -> ChunkedVec<String, N> {
+        let mut vec = ChunkedVecSized::new();
+        vec.extend(range.map(|i| i.to_string()));
+        vec
+    }
+
+    fn expected(range: std::ops::Range<usize>) -> Vec<String> {
+        range.map(|i| i.to_string()).collect()
+    }
+
+    #[test]
+    fn test_append_matches_vec() {
+        for a in 0..10 {
+            for b in 0..10 {
+                let mut vec = strings::<3>(0..a);
+                let mut other = strings::<3>(a..a + b);
+                vec.append(&mut other);
+                assert_eq!(vec, expected(0..a + b), "a = {a}, b = {b}");
+                assert!(other.is_empty());
+
+                // Both halves stay usable afterwards
+                other.push("x".to_string());
+                vec.push("y".to_string());
+                assert_eq!(other, ["x"]);
+                assert_eq!(vec.len(), a + b + 1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_append_aligned_moves_chunks() {
+        let mut vec = strings::<4>(0..8);
+        let mut other = strings::<4>(8..14);
+        let moved: Vec<*const String> = other.chunks().map(|c| c.as_ptr()).collect();
+
+        vec.append(&mut other);
+        assert_eq!(vec, expected(0..14));
+        assert_eq!(vec.chunk(2).unwrap().as_ptr(), moved[0]);
+        assert_eq!(vec.chunk(3).unwrap().as_ptr(), moved[1]);
+        assert_eq!(other.allocated_capacity(), 0);
+    }
+
+    #[test]
+    fn test_append_keeps_retained_chunks() {
+        let mut vec = strings::<2>(0..6);
+        vec.truncate(2);
+        let mut other = strings::<2>(2..7);
+        other.truncate(3);
+
+        vec.append(&mut other);
+        assert_eq!(vec, expected(0..5));
+        // The two retained chunks of `vec` follow the moved ones
+        assert_eq!(vec.allocated_capacity(), 10);
+        // `other` keeps the chunk it did not hand over
+        assert_eq!(other.allocated_capacity(), 2);
+    }
+
+    #[test]
+    fn test_split_off_matches_vec() {
+        for len in 0..12 {
+            for at in 0..=len {
+                let mut vec = strings::<3>(0..len);
+                let tail = vec.split_off(at);
+                assert_eq!(vec, expected(0..at), "len = {len}, at = {at}");
+                assert_eq!(tail, expected(at..len), "len = {len}, at = {at}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_split_off_aligned_moves_chunks() {
+        let mut vec = strings::<4>(0..10);
+        let second = vec.chunk(1).unwrap().as_ptr();
+
+        let tail = vec.split_off(4);
+        assert_eq!(tail.chunk(0).unwrap().as_ptr(), second);
+        assert_eq!(vec.allocated_capacity(), 4);
+        assert_eq!(tail.allocated_capacity(), 8);
+    }
+
+    #[test]
+    #[should_panic(expected = "`at` split index (is 4) should be <= len (is 3)")]
+    fn test_split_off_out_of_bounds() {
+        let mut vec: ChunkedVec<i32, 2> = ChunkedVecSized::new();
+        vec.extend([1, 2, 3]);
+        let _ = vec.split_off(4);
+    }
+
+    #[test]
+    fn test_extend_from_slice_matches_vec() {
+        let source = expected(0..20);
+        for start in 0..8 {
+            for count in 0..source.len() {
+                let mut vec = strings::<4>(0..start);
+                vec.extend_from_slice(&source[..count]);
+                let mut want = expected(0..start);
+                want.extend_from_slice(&source[..count]);
+                assert_eq!(vec, want);
+                assert_eq!(vec.allocated_capacity(), vec.len().div_ceil(4) * 4);
+            }
+        }
+    }
+
+    #[test]
+    fn test_extend_from_slice_fills_retained_chunks() {
+        let mut vec = strings::<4>(0..12);
+        vec.truncate(1);
+        vec.extend_from_slice(&expected(1..10));
+        assert_eq!(vec, expected(0..10));
+        assert_eq!(vec.allocated_capacity(), 12);
+    }
+
+    #[test]
+    fn test_extend_from_slice_panicking_clone() {
+        use std::cell::Cell;
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        use std::rc::Rc;
+
+        struct Bomb {
+            live: Rc<Cell<usize>>,
+            explode: bool,
+        }
+
+        impl Clone for Bomb {
+            fn clone(&self) -> Self {
+                if self.explode {
+                    panic!("clone failed");
+                }
+                self.live.set(self.live.get() + 1);
+                Bomb {
+                    live: self.live.clone(),
+                    explode: false,
+                }
+            }
+        }
+
+        impl Drop for Bomb {
+            fn drop(&mut self) {
+                self.live.set(self.live.get() - 1);
+            }
+        }
+
+        let live = Rc::new(Cell::new(0));
+        let source: Vec<Bomb> = (0..12)
+            .map(|i| {
+                live.set(live.get() + 1);
+                Bomb {
+                    live: live.clone(),
+                    explode: i == 2 || i == 7,
+                }
+            })
+            .collect();
+
+        let mut vec: ChunkedVec<Bomb, 4> = ChunkedVecSized::new();
+        vec.extend_from_slice(&source[..1]);
+        // Panics while filling the first chunk element by element
+        let result = catch_unwind(AssertUnwindSafe(|| vec.extend_from_slice(&source[1..])));
+        assert!(result.is_err());
+        assert_eq!(vec.len(), 2);
+        assert_eq!(live.get(), 14);
+
+        // Panics inside the bulk clone of a whole chunk
+        let result = catch_unwind(AssertUnwindSafe(|| vec.extend_from_slice(&source[3..])));
+        assert!(result.is_err());
+        assert_eq!(vec.len(), 4);
+        assert_eq!(live.get(), 16);
+
+        drop(vec);
+        assert_eq!(live.get(), 12);
+    }
+
+    #[test]
+    fn test_append_vec_keeps_capacity() {
+        let mut vec = strings::<3>(0..4);
+        let mut other = expected(4..11);
+        let capacity = other.capacity();
+
+        vec.append_vec(&mut other);
+        assert_eq!(vec, expected(0..11));
+        assert!(other.is_empty());
+        assert_eq!(other.capacity(), capacity);
+
+        vec.append_vec(&mut other);
+        assert_eq!(vec.len(), 11);
+    }
+
+    #[test]
+    fn test_reserve_allocates_chunks() {
+        let mut vec: ChunkedVec<i32, 4> = ChunkedVecSized::new();
+        vec.reserve(0);
+        assert_eq!(vec.allocated_capacity(), 0);
+
+        vec.push(1);
+        vec.reserve(7);
+        assert_eq!(vec.allocated_capacity(), 8);
+        let chunk = vec.chunk(0).unwrap().as_ptr();
+        vec.extend(2..=8);
+        assert_eq!(vec.allocated_capacity(), 8);
+        assert_eq!(vec.chunk(0).unwrap().as_ptr(), chunk);
+
+        // Already enough room in retained chunks
+        vec.truncate(2);
+        vec.reserve(6);
+        assert_eq!(vec.allocated_capacity(), 8);
+
+        vec.reserve_exact(10);
+        assert_eq!(vec.allocated_capacity(), 12);
+        assert!(vec.capacity() >= vec.allocated_capacity());
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity overflow")]
+    fn test_reserve_overflow() {
+        let mut vec: ChunkedVec<i32, 4> = ChunkedVecSized::new();
+        vec.push(1);
+        vec.reserve(usize::MAX);
+    }
+
+    #[test]
+    fn test_try_reserve() {
+        let mut vec: ChunkedVec<u64, 4> = ChunkedVecSized::new();
+        vec.extend(0..3);
+        assert!(vec.try_reserve(6).is_ok());
+        assert_eq!(vec.allocated_capacity(), 12);
+
+        // Length overflow
+        assert!(vec.try_reserve(usize::MAX).is_err());
+        // The chunk table alone would exceed `isize::MAX` bytes
+        assert!(vec.try_reserve(usize::MAX / 2).is_err());
+        assert_eq!(vec.allocated_capacity(), 12);
+        assert!(vec.iter().copied().eq(0..3));
+    }
+
+    #[test]
+    fn test_shrink_to() {
+        let mut vec: ChunkedVec<i32, 3> = ChunkedVecSized::new();
+        vec.extend(0..15);
+        vec.truncate(4);
+
+        // Never below the bound
+        vec.shrink_to(8);
+        assert_eq!(vec.allocated_capacity(), 9);
+        assert!(vec.capacity() >= 8);
+
+        // A bound above the capacity is a no-op
+        vec.shrink_to(100);
+        assert_eq!(vec.allocated_capacity(), 9);
+
+        // Never below the length
+        vec.shrink_to(0);
+        assert_eq!(vec.allocated_capacity(), 6);
+        assert_eq!(vec, [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_growth_keeps_element_addresses() {
+        let mut vec: ChunkedVec<String, 4> = ChunkedVecSized::new();
+        vec.extend((0..6).map(|i| i.to_string()));
+        let addrs: Vec<*const String> = vec.iter().map(|s| s as *const String).collect();
+        let same = |vec: &ChunkedVec<String, 4>| {
+            addrs
+                .iter()
+                .enumerate()
+                .all(|(i, &addr)| ptr::eq(&vec[i], addr))
+        };
+
+        for i in 6..200 {
+            vec.push(i.to_string());
+        }
+        assert!(same(&vec));
+        *vec.push_get("x".to_string()) += "y";
+        assert!(same(&vec));
+        vec.extend_from_slice(&["a".to_string(), "b".to_string()]);
+        vec.reserve(1000);
+        vec.try_reserve(10).unwrap();
+        vec.resize(300, String::new());
+        assert!(same(&vec));
+
+        vec.truncate(6);
+        vec.shrink_to_fit();
+        vec.pop();
+        assert!(addrs[..5]
+            .iter()
+            .enumerate()
+            .all(|(i, &addr)| ptr::eq(&vec[i], addr)));
+
+        let mut other: ChunkedVec<String, 4> = ChunkedVecSized::new();
+        other.extend((0..9).map(|i| i.to_string()));
+        vec.append(&mut other);
+        let tail = vec.split_off(5);
+        assert!(addrs[..5]
+            .iter()
+            .enumerate()
+            .all(|(i, &addr)| ptr::eq(&vec[i], addr)));
+        assert_eq!(tail.len(), 9);
+        assert_eq!(vec.push_get("z".to_string()), "z");
+    }
 }
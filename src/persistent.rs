@@ -0,0 +1,649 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::mem::{self, ManuallyDrop};
+use std::ops::{Index, IndexMut};
+use std::ptr;
+use std::slice;
+use std::sync::Arc;
+
+use crate::{ChunkedVec, ChunkedVecSized};
+
+/// A copy-on-write vector whose chunks are shared between clones.
+///
+/// Full chunks are stored as `Arc<[T; N]>`, and the last, partially filled
+/// chunk as a shared tail. Cloning the vector therefore clones one pointer
+/// per chunk and never an element. Mutating methods ([`set`], [`get_mut`],
+/// [`push`], [`pop`]) go through [`Arc::make_mut`], so they copy only the
+/// chunk they touch, and only if another clone still shares it.
+///
+/// This makes cheap snapshots, e.g. for undo history: keep a clone of the
+/// vector before each edit, and each snapshot costs one chunk table plus the
+/// chunks that were edited since.
+///
+/// Converting from a [`ChunkedVec`] moves every element with one `memcpy` per
+/// chunk. Converting back does the same for every chunk that is not shared
+/// (see [`try_into_chunked_vec`] and [`into_chunked_vec`]).
+///
+/// [`set`]: PersistentChunkedVec::set
+/// [`get_mut`]: PersistentChunkedVec::get_mut
+/// [`push`]: PersistentChunkedVec::push
+/// [`pop`]: PersistentChunkedVec::pop
+/// [`try_into_chunked_vec`]: PersistentChunkedVec::try_into_chunked_vec
+/// [`into_chunked_vec`]: PersistentChunkedVec::into_chunked_vec
+///
+/// # Examples
+/// ```
+/// use chunked_vec::PersistentChunkedVec;
+///
+/// let mut state: PersistentChunkedVec<i32, 4> = (0..10).collect();
+/// let snapshot = state.clone();
+///
+/// state.set(1, 100);
+/// state.push(10);
+///
+/// assert_eq!(state[1], 100);
+/// assert_eq!(state.len(), 11);
+/// // The snapshot is unaffected
+/// assert_eq!(snapshot[1], 1);
+/// assert!(snapshot.iter().copied().eq(0..10));
+/// ```
+pub struct PersistentChunkedVec<T, const N: usize = { crate::DEFAULT_CHUNK_SIZE }> {
+    /// The full chunks.
+    chunks: Vec<Arc<[T; N]>>,
+    /// The elements after the last full chunk, always fewer than `N`.
+    tail: Arc<Vec<T>>,
+}
+
+impl<T, const N: usize> PersistentChunkedVec<T, N> {
+    /// Creates an empty vector. No memory is allocated until the first push.
+    pub fn new() -> Self {
+        PersistentChunkedVec {
+            chunks: Vec::new(),
+            tail: Arc::new(Vec::new()),
+        }
+    }
+
+    /// Returns the number of elements in the vector.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.chunks.len() * N + self.tail.len()
+    }
+
+    /// Returns `true` if the vector contains no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a reference to the element at `index`, or `None` if it is out
+    /// of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::PersistentChunkedVec;
+    ///
+    /// let vec: PersistentChunkedVec<i32, 2> = (1..=3).collect();
+    /// assert_eq!(vec.get(2), Some(&3));
+    /// assert_eq!(vec.get(3), None);
+    /// ```
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        match self.chunks.get(index / N) {
+            Some(chunk) => Some(&chunk[index % N]),
+            None => self.tail.get(index - self.chunks.len() * N),
+        }
+    }
+
+    /// Returns a mutable reference to the element at `index`, or `None` if
+    /// it is out of bounds.
+    ///
+    /// If the chunk holding the element is shared with a clone, it is copied
+    /// first.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::PersistentChunkedVec;
+    ///
+    /// let mut vec: PersistentChunkedVec<i32, 2> = (1..=3).collect();
+    /// let snapshot = vec.clone();
+    /// *vec.get_mut(0).unwrap() += 10;
+    /// assert_eq!(vec[0], 11);
+    /// assert_eq!(snapshot[0], 1);
+    /// ```
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T>
+    where
+        T: Clone,
+    {
+        let full = self.chunks.len() * N;
+        if index < full {
+            Some(&mut Arc::make_mut(&mut self.chunks[index / N])[index % N])
+        } else if index - full < self.tail.len() {
+            Some(&mut Arc::make_mut(&mut self.tail)[index - full])
+        } else {
+            None
+        }
+    }
+
+    /// Replaces the element at `index` with `value`, copying its chunk first
+    /// if a clone shares it.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn set(&mut self, index: usize, value: T)
+    where
+        T: Clone,
+    {
+        self[index] = value;
+    }
+
+    /// Appends an element to the back of the vector.
+    ///
+    /// Only the tail is copied if a clone shares it; full chunks are never
+    /// touched.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::PersistentChunkedVec;
+    ///
+    /// let mut vec = PersistentChunkedVec::<i32, 2>::new();
+    /// vec.push(1);
+    /// vec.push(2);
+    /// vec.push(3);
+    /// assert_eq!(vec.len(), 3);
+    /// assert_eq!(vec[2], 3);
+    /// ```
+    pub fn push(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        let tail = Arc::make_mut(&mut self.tail);
+        if tail.capacity() == 0 {
+            tail.reserve_exact(N);
+        }
+        tail.push(value);
+        if tail.len() == N {
+            let chunk: Box<[T; N]> = match mem::take(tail).into_boxed_slice().try_into() {
+                Ok(chunk) => chunk,
+                Err(_) => unreachable!("the tail holds exactly N elements"),
+            };
+            // Moves the elements into the `Arc` allocation without cloning
+            self.chunks.push(Arc::from(chunk));
+        }
+    }
+
+    /// Removes the last element and returns it, or `None` if the vector is
+    /// empty.
+    ///
+    /// When the tail is empty, the last full chunk becomes the new tail; it
+    /// is copied only if a clone shares it.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::PersistentChunkedVec;
+    ///
+    /// let mut vec: PersistentChunkedVec<i32, 2> = (1..=3).collect();
+    /// assert_eq!(vec.pop(), Some(3));
+    /// assert_eq!(vec.pop(), Some(2));
+    /// assert_eq!(vec.len(), 1);
+    /// ```
+    pub fn pop(&mut self) -> Option<T>
+    where
+        T: Clone,
+    {
+        if self.tail.is_empty() {
+            let chunk = self.chunks.pop()?;
+            let tail = Arc::make_mut(&mut self.tail);
+            tail.reserve_exact(N);
+            match take_chunk(chunk, tail.as_mut_ptr()) {
+                // Safety: the chunk's elements were moved into the tail
+                Ok(()) => unsafe { tail.set_len(N) },
+                Err(chunk) => tail.extend_from_slice(&chunk[..]),
+            }
+        }
+        Arc::make_mut(&mut self.tail).pop()
+    }
+
+    /// Returns an iterator over the elements of the vector.
+    pub fn iter(&self) -> PersistentIter<'_, T, N> {
+        PersistentIter {
+            front: [].iter(),
+            chunks: self.chunks.iter(),
+            back: self.tail.iter(),
+        }
+    }
+
+    /// Converts the vector into a `ChunkedVec`, moving the elements with one
+    /// `memcpy` per chunk, or gives it back unchanged if any chunk is shared
+    /// with a clone.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkedVec, PersistentChunkedVec};
+    ///
+    /// let vec: PersistentChunkedVec<String, 2> =
+    ///     ["a", "b", "c"].map(String::from).into_iter().collect();
+    /// let snapshot = vec.clone();
+    ///
+    /// // Both share their chunks
+    /// let vec = vec.try_into_chunked_vec().unwrap_err();
+    /// drop(snapshot);
+    ///
+    /// let vec: ChunkedVec<String, 2> = vec.try_into_chunked_vec().unwrap();
+    /// assert_eq!(vec, ["a", "b", "c"]);
+    /// ```
+    pub fn try_into_chunked_vec(mut self) -> Result<ChunkedVec<T, N>, Self> {
+        let unique = self
+            .chunks
+            .iter_mut()
+            .all(|chunk| Arc::get_mut(chunk).is_some());
+        if !unique || Arc::get_mut(&mut self.tail).is_none() {
+            return Err(self);
+        }
+
+        let mut vec = ChunkedVecSized::<T, N>::with_capacity(self.len());
+        for chunk in self.chunks {
+            let mut dst = ChunkedVec::<T, N>::create_empty_chunk();
+            if take_chunk(chunk, dst.as_mut_ptr().cast()).is_err() {
+                unreachable!("every chunk is uniquely owned");
+            }
+            vec.data.push(dst);
+            vec.len += N;
+        }
+        match Arc::try_unwrap(self.tail) {
+            Ok(mut tail) => vec.append_vec(&mut tail),
+            Err(_) => unreachable!("the tail is uniquely owned"),
+        }
+        Ok(vec)
+    }
+
+    /// Converts the vector into a `ChunkedVec`.
+    ///
+    /// Chunks that are not shared are moved with one `memcpy` each, and
+    /// shared ones are cloned.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkedVec, PersistentChunkedVec};
+    ///
+    /// let vec: PersistentChunkedVec<i32, 2> = (1..=5).collect();
+    /// let snapshot = vec.clone();
+    ///
+    /// let vec: ChunkedVec<i32, 2> = vec.into_chunked_vec();
+    /// assert_eq!(vec, [1, 2, 3, 4, 5]);
+    /// assert_eq!(snapshot.len(), 5);
+    /// ```
+    pub fn into_chunked_vec(mut self) -> ChunkedVec<T, N>
+    where
+        T: Clone,
+    {
+        for chunk in &mut self.chunks {
+            Arc::make_mut(chunk);
+        }
+        Arc::make_mut(&mut self.tail);
+        match self.try_into_chunked_vec() {
+            Ok(vec) => vec,
+            Err(_) => unreachable!("every chunk is uniquely owned"),
+        }
+    }
+}
+
+/// Moves the elements of a uniquely owned chunk to `dst` and frees the
+/// chunk, or hands it back if it is shared.
+fn take_chunk<T, const N: usize>(mut chunk: Arc<[T; N]>, dst: *mut T) -> Result<(), Arc<[T; N]>> {
+    if Arc::get_mut(&mut chunk).is_none() {
+        return Err(chunk);
+    }
+    let raw = Arc::into_raw(chunk);
+    // Safety: this is the only reference to the chunk, so nothing reads the
+    // elements after they are moved out. `ManuallyDrop<T>` has the layout of
+    // `T`, so the chunk is freed as the same type, without dropping the
+    // moved elements.
+    unsafe {
+        ptr::copy_nonoverlapping(raw.cast::<T>(), dst, N);
+        drop(Arc::from_raw(raw.cast::<[ManuallyDrop<T>; N]>()));
+    }
+    Ok(())
+}
+
+impl<T, const N: usize> Default for PersistentChunkedVec<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Clones the chunk table, sharing every chunk with the original.
+impl<T, const N: usize> Clone for PersistentChunkedVec<T, N> {
+    fn clone(&self) -> Self {
+        PersistentChunkedVec {
+            chunks: self.chunks.clone(),
+            tail: self.tail.clone(),
+        }
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for PersistentChunkedVec<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for PersistentChunkedVec<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for PersistentChunkedVec<T, N> {}
+
+impl<T, const N: usize> Index<usize> for PersistentChunkedVec<T, N> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        match self.get(index) {
+            Some(elem) => elem,
+            None => panic!(
+                "Index out of bounds: index {} >= length {}",
+                index,
+                self.len()
+            ),
+        }
+    }
+}
+
+/// Copies the chunk holding the element first if a clone shares it.
+impl<T: Clone, const N: usize> IndexMut<usize> for PersistentChunkedVec<T, N> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let len = self.len();
+        match self.get_mut(index) {
+            Some(elem) => elem,
+            None => panic!("Index out of bounds: index {} >= length {}", index, len),
+        }
+    }
+}
+
+/// Moves the elements of a `ChunkedVec` in, with one `memcpy` per chunk.
+///
+/// # Examples
+/// ```
+/// use chunked_vec::{chunked_vec, PersistentChunkedVec};
+///
+/// let vec = PersistentChunkedVec::from(chunked_vec![1, 2, 3]);
+/// assert_eq!(vec[2], 3);
+/// ```
+impl<T, const N: usize> From<ChunkedVec<T, N>> for PersistentChunkedVec<T, N> {
+    fn from(mut vec: ChunkedVec<T, N>) -> Self {
+        let full = vec.len / N;
+        let rest = vec.len % N;
+        // From here on the elements belong to the chunks built below
+        vec.len = 0;
+        let mut data = mem::take(&mut vec.data);
+
+        let mut tail = Vec::with_capacity(N);
+        if rest > 0 {
+            // Safety: the first `rest` slots of chunk `full` are initialized
+            // and no longer counted by `vec`.
+            unsafe {
+                ptr::copy_nonoverlapping(data[full].as_ptr().cast::<T>(), tail.as_mut_ptr(), rest);
+                tail.set_len(rest);
+            }
+        }
+        data.truncate(full);
+
+        let chunks = data
+            .into_iter()
+            .map(|chunk| {
+                // Safety: every slot of a full chunk is initialized, and
+                // `[MaybeUninit<T>; N]` has the layout of `[T; N]`.
+                let chunk: Box<[T; N]> = unsafe { Box::from_raw(Box::into_raw(chunk).cast()) };
+                Arc::from(chunk)
+            })
+            .collect();
+        PersistentChunkedVec {
+            chunks,
+            tail: Arc::new(tail),
+        }
+    }
+}
+
+/// Same as [`PersistentChunkedVec::into_chunked_vec`].
+impl<T: Clone, const N: usize> From<PersistentChunkedVec<T, N>> for ChunkedVec<T, N> {
+    fn from(vec: PersistentChunkedVec<T, N>) -> Self {
+        vec.into_chunked_vec()
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for PersistentChunkedVec<T, N> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        ChunkedVec::<T, N>::from_iter(iter).into()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a PersistentChunkedVec<T, N> {
+    type Item = &'a T;
+    type IntoIter = PersistentIter<'a, T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the elements of a [`PersistentChunkedVec`].
+///
+/// Created by [`PersistentChunkedVec::iter`].
+pub struct PersistentIter<'a, T, const N: usize> {
+    /// The rest of the chunk at the front.
+    front: slice::Iter<'a, T>,
+    /// The chunks between `front` and `back`.
+    chunks: slice::Iter<'a, Arc<[T; N]>>,
+    /// The rest of the chunk (or tail) at the back.
+    back: slice::Iter<'a, T>,
+}
+
+impl<T, const N: usize> Clone for PersistentIter<'_, T, N> {
+    fn clone(&self) -> Self {
+        PersistentIter {
+            front: self.front.clone(),
+            chunks: self.chunks.clone(),
+            back: self.back.clone(),
+        }
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for PersistentIter<'a, T, N> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        loop {
+            if let Some(elem) = self.front.next() {
+                return Some(elem);
+            }
+            match self.chunks.next() {
+                Some(chunk) => self.front = chunk.iter(),
+                None => return self.back.next(),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for PersistentIter<'_, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(elem) = self.back.next_back() {
+                return Some(elem);
+            }
+            match self.chunks.next_back() {
+                Some(chunk) => self.back = chunk.iter(),
+                None => return self.front.next_back(),
+            }
+        }
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for PersistentIter<'_, T, N> {
+    fn len(&self) -> usize {
+        self.front.len() + self.chunks.len() * N + self.back.len()
+    }
+}
+
+impl<T, const N: usize> FusedIterator for PersistentIter<'_, T, N> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chunked_vec;
+    use std::rc::Rc;
+
+    fn shares_chunk<T, const N: usize>(
+        a: &PersistentChunkedVec<T, N>,
+        b: &PersistentChunkedVec<T, N>,
+        chunk: usize,
+    ) -> bool {
+        Arc::ptr_eq(&a.chunks[chunk], &b.chunks[chunk])
+    }
+
+    #[test]
+    fn test_push_get_and_index() {
+        let mut vec = PersistentChunkedVec::<usize, 3>::new();
+        assert!(vec.is_empty());
+        for i in 0..10 {
+            vec.push(i);
+            assert_eq!(vec.len(), i + 1);
+        }
+        assert_eq!(vec.chunks.len(), 3);
+        assert_eq!(vec.tail.len(), 1);
+        for i in 0..10 {
+            assert_eq!(vec[i], i);
+            assert_eq!(vec.get(i), Some(&i));
+        }
+        assert_eq!(vec.get(10), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index out of bounds: index 4 >= length 4")]
+    fn test_index_out_of_bounds() {
+        let vec: PersistentChunkedVec<i32, 2> = (0..4).collect();
+        let _ = vec[4];
+    }
+
+    #[test]
+    fn test_clone_shares_every_chunk() {
+        let vec: PersistentChunkedVec<String, 2> = (0..5).map(|i| i.to_string()).collect();
+        let snapshot = vec.clone();
+        for chunk in 0..2 {
+            assert!(shares_chunk(&vec, &snapshot, chunk));
+        }
+        assert!(Arc::ptr_eq(&vec.tail, &snapshot.tail));
+    }
+
+    #[test]
+    fn test_set_copies_only_touched_chunk() {
+        let mut vec: PersistentChunkedVec<i32, 2> = (0..6).collect();
+        let snapshot = vec.clone();
+
+        vec.set(3, 30);
+        assert!(shares_chunk(&vec, &snapshot, 0));
+        assert!(!shares_chunk(&vec, &snapshot, 1));
+        assert!(shares_chunk(&vec, &snapshot, 2));
+        assert_eq!(vec, [0, 1, 2, 30, 4, 5].into_iter().collect());
+        assert!(snapshot.iter().copied().eq(0..6));
+
+        // A chunk no longer shared is written in place
+        let chunk = Arc::as_ptr(&vec.chunks[1]);
+        vec[2] = 20;
+        assert_eq!(Arc::as_ptr(&vec.chunks[1]), chunk);
+        assert_eq!(vec[2], 20);
+    }
+
+    #[test]
+    fn test_push_and_pop_leave_snapshot_intact() {
+        let mut vec: PersistentChunkedVec<i32, 2> = (0..3).collect();
+        let snapshot = vec.clone();
+
+        vec.push(3);
+        vec.push(4);
+        assert!(shares_chunk(&vec, &snapshot, 0));
+        assert_eq!(vec.len(), 5);
+
+        let mut popped = Vec::new();
+        while let Some(elem) = vec.pop() {
+            popped.push(elem);
+        }
+        assert_eq!(popped, [4, 3, 2, 1, 0]);
+        assert!(snapshot.iter().copied().eq(0..3));
+    }
+
+    #[test]
+    fn test_pop_moves_unshared_chunk_into_tail() {
+        let mut vec: PersistentChunkedVec<Rc<()>, 2> = PersistentChunkedVec::new();
+        let value = Rc::new(());
+        for _ in 0..4 {
+            vec.push(value.clone());
+        }
+        assert_eq!(Rc::strong_count(&value), 5);
+        drop(vec.pop());
+        drop(vec.pop());
+        drop(vec.pop());
+        // Nothing was cloned when chunks became the tail
+        assert_eq!(Rc::strong_count(&value), 2);
+        drop(vec);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn test_iter_both_ends() {
+        let vec: PersistentChunkedVec<i32, 3> = (0..10).collect();
+        assert!(vec.iter().copied().eq(0..10));
+        assert!(vec.iter().rev().copied().eq((0..10).rev()));
+
+        let mut iter = vec.iter();
+        assert_eq!(iter.len(), 10);
+        assert_eq!(iter.next(), Some(&0));
+        assert_eq!(iter.next_back(), Some(&9));
+        assert_eq!(iter.len(), 8);
+        let middle: Vec<_> = iter.copied().collect();
+        assert_eq!(middle, [1, 2, 3, 4, 5, 6, 7, 8]);
+
+        let empty = PersistentChunkedVec::<i32, 3>::new();
+        assert_eq!(empty.iter().next(), None);
+    }
+
+    #[test]
+    fn test_round_trip_moves_elements() {
+        for len in [0, 1, 4, 5] {
+            let value = Rc::new(());
+            let vec: ChunkedVec<Rc<()>, 4> = (0..len).map(|_| value.clone()).collect();
+            let first = vec.chunk(0).map(|chunk| chunk.as_ptr());
+
+            let persistent = PersistentChunkedVec::from(vec);
+            assert_eq!(persistent.len(), len);
+            let vec = persistent.try_into_chunked_vec().ok().unwrap();
+            assert_eq!(vec.len(), len);
+            assert_eq!(vec.chunk(0).is_some(), first.is_some());
+            // Elements were moved, never cloned
+            assert_eq!(Rc::strong_count(&value), len + 1);
+            drop(vec);
+            assert_eq!(Rc::strong_count(&value), 1);
+        }
+    }
+
+    #[test]
+    fn test_into_chunked_vec_clones_shared_chunks() {
+        let vec: PersistentChunkedVec<String, 2> = (0..5).map(|i| i.to_string()).collect();
+        let mut snapshot = vec.clone();
+        snapshot.set(0, "zero".to_string());
+
+        // Chunk 1 and the tail are still shared
+        let vec = vec.try_into_chunked_vec().unwrap_err();
+        let vec = vec.into_chunked_vec();
+        assert_eq!(vec, chunked_vec!["0", "1", "2", "3", "4"]);
+        assert_eq!(snapshot[0], "zero");
+        assert_eq!(snapshot[4], "4");
+    }
+}
@@ -0,0 +1,180 @@
+use std::pin::Pin;
+
+use crate::ChunkedVec;
+
+/// A `ChunkedVec` is `Unpin` only when its elements are.
+///
+/// Without this, the vector would always be `Unpin`, because its elements
+/// live behind boxes. A pinned vector of `!Unpin` elements therefore never
+/// hands out `&mut ChunkedVec`, which keeps every method that moves elements
+/// (`pop`, `remove`, `sort`, ...) out of reach while it is pinned.
+impl<T: Unpin, const N: usize> Unpin for ChunkedVec<T, N> {}
+
+/// Implementation of pinned element access for ChunkedVec.
+///
+/// Elements never move when the vector grows (see [stable element
+/// addresses](ChunkedVec#stable-element-addresses)), and dropping the
+/// vector drops every element in place, even if one destructor panics. A
+/// pinned vector can therefore hand out pinned references to its elements.
+impl<T, const N: usize> ChunkedVec<T, N> {
+    /// Appends an element to the back of a pinned vector and returns a
+    /// pinned reference to it.
+    ///
+    /// This is how `!Unpin` values, such as self-referential structs or
+    /// intrusive list nodes, are stored: the element is pinned in place from
+    /// the moment it is pushed.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::marker::PhantomPinned;
+    /// use std::pin::{pin, Pin};
+    /// use chunked_vec::{ChunkedVec, ChunkedVecSized};
+    ///
+    /// struct Node {
+    ///     value: i32,
+    ///     _pin: PhantomPinned,
+    /// }
+    ///
+    /// let mut vec = pin!(ChunkedVecSized::<Node, 2>::new());
+    /// for value in 0..5 {
+    ///     let node: Pin<&mut Node> = vec.as_mut().push_pinned(Node {
+    ///         value,
+    ///         _pin: PhantomPinned,
+    ///     });
+    ///     assert_eq!(node.value, value);
+    /// }
+    /// assert_eq!(vec.len(), 5);
+    /// ```
+    pub fn push_pinned(self: Pin<&mut Self>, value: T) -> Pin<&mut T> {
+        // Safety: the vector is not moved out of the pin, and pushing does
+        // not move the elements already in it.
+        unsafe { Pin::new_unchecked(self.get_unchecked_mut().push_get(value)) }
+    }
+
+    /// Returns a pinned mutable reference to the element at `index`, or
+    /// `None` if the index is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::pin::pin;
+    /// use chunked_vec::{ChunkedVec, ChunkedVecSized};
+    ///
+    /// let mut vec = pin!(ChunkedVecSized::<i32, 2>::new());
+    /// vec.as_mut().push_pinned(1);
+    /// vec.as_mut().push_pinned(2);
+    ///
+    /// *vec.as_mut().get_pin_mut(1).unwrap() += 10;
+    /// assert_eq!(vec[1], 12);
+    /// assert!(vec.as_mut().get_pin_mut(2).is_none());
+    /// ```
+    pub fn get_pin_mut(self: Pin<&mut Self>, index: usize) -> Option<Pin<&mut T>> {
+        // Safety: elements of a pinned vector are pinned; the reference is
+        // only handed out pinned.
+        unsafe {
+            self.get_unchecked_mut()
+                .get_mut(index)
+                .map(|elem| Pin::new_unchecked(elem))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ChunkedVec, ChunkedVecSized};
+    use std::cell::Cell;
+    use std::marker::PhantomPinned;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use std::pin::{pin, Pin};
+    use std::ptr;
+
+    /// Remembers its own address when asked, and checks it was not moved
+    /// since.
+    struct SelfRef {
+        value: usize,
+        this: *const SelfRef,
+        _pin: PhantomPinned,
+    }
+
+    impl SelfRef {
+        fn new(value: usize) -> Self {
+            SelfRef {
+                value,
+                this: ptr::null(),
+                _pin: PhantomPinned,
+            }
+        }
+
+        fn bind(self: Pin<&mut Self>) {
+            // Safety: only a plain field is written, nothing is moved.
+            let this = unsafe { self.get_unchecked_mut() };
+            let addr: *const SelfRef = this;
+            this.this = addr;
+        }
+
+        fn is_in_place(&self) -> bool {
+            ptr::eq(self.this, self)
+        }
+    }
+
+    #[test]
+    fn test_pinned_elements_stay_in_place() {
+        let mut vec = pin!(ChunkedVecSized::<SelfRef, 3>::new());
+        for i in 0..100 {
+            vec.as_mut().push_pinned(SelfRef::new(i)).bind();
+        }
+
+        // Growing reallocated the chunk table many times
+        for i in 0..100 {
+            let elem = vec.as_mut().get_pin_mut(i).unwrap();
+            assert_eq!(elem.value, i);
+            assert!(elem.is_in_place());
+        }
+        assert!(vec.iter().all(SelfRef::is_in_place));
+    }
+
+    #[test]
+    fn test_unpin_follows_element_type() {
+        fn is_unpin<T: Unpin>() {}
+        is_unpin::<ChunkedVec<i32, 4>>();
+        is_unpin::<ChunkedVec<String>>();
+
+        // Unpin elements can be used with the pinned API as well
+        let mut vec: ChunkedVec<i32, 4> = ChunkedVecSized::new();
+        Pin::new(&mut vec).push_pinned(1);
+        vec.push(2);
+        assert_eq!(vec, [1, 2]);
+    }
+
+    #[test]
+    fn test_pinned_elements_dropped_in_place_on_panic() {
+        struct Tracked<'a> {
+            drops: &'a Cell<usize>,
+            panic: bool,
+            _pin: PhantomPinned,
+        }
+
+        impl Drop for Tracked<'_> {
+            fn drop(&mut self) {
+                self.drops.set(self.drops.get() + 1);
+                if self.panic {
+                    panic!("drop failed");
+                }
+            }
+        }
+
+        let drops = Cell::new(0);
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            let mut vec = Box::pin(ChunkedVecSized::<Tracked, 2>::new());
+            for i in 0..7 {
+                vec.as_mut().push_pinned(Tracked {
+                    drops: &drops,
+                    panic: i == 1,
+                    _pin: PhantomPinned,
+                });
+            }
+        }));
+        assert!(result.is_err());
+        // Every element's destructor ran, including those after the panic
+        assert_eq!(drops.get(), 7);
+    }
+}
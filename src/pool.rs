@@ -0,0 +1,332 @@
+use crate::{Chunk, ChunkedVec, ChunkedVecSized};
+
+/// Counters describing how a [`ChunkPool`] has been used.
+///
+/// Returned by [`ChunkPool::stats`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PoolStats {
+    /// Chunks handed out from the pool.
+    pub hits: usize,
+    /// Chunks that had to be allocated because the pool was empty.
+    pub misses: usize,
+    /// Chunks given back and kept by the pool.
+    pub recycled: usize,
+    /// Chunks given back but freed because the pool was full.
+    pub discarded: usize,
+}
+
+/// A bounded pool of empty chunks shared between vectors.
+///
+/// Vectors that are created and dropped in a hot loop can draw their chunks
+/// from a pool and give them back when they are done, so the allocator is
+/// only involved when the pool runs dry. A vector's own retained chunks
+/// (kept by [`clear`] and [`truncate`]) already act as a per-vector free
+/// list; the pool lets chunks move between vectors.
+///
+/// The pool holds at most [`max_chunks`] chunks. Chunks given back beyond
+/// that bound are freed.
+///
+/// The pool is a plain value: vectors do not keep a reference to it, and
+/// chunks only move when it is passed explicitly. Wrap it in a `Mutex` to
+/// share it between threads.
+///
+/// [`clear`]: ChunkedVec::clear
+/// [`truncate`]: ChunkedVec::truncate
+/// [`max_chunks`]: ChunkPool::max_chunks
+///
+/// # Examples
+/// ```
+/// use chunked_vec::{ChunkPool, ChunkedVec};
+///
+/// let mut pool: ChunkPool<u32, 16> = ChunkPool::new(8);
+/// for tick in 0..3 {
+///     let mut vec: ChunkedVec<u32, 16> = pool.vec_with_capacity(40);
+///     vec.extend(0..40);
+///     pool.recycle(vec);
+/// }
+///
+/// let stats = pool.stats();
+/// assert_eq!(stats.misses, 3); // only the first tick allocated
+/// assert_eq!(stats.hits, 6);
+/// assert_eq!(pool.len(), 3);
+/// ```
+pub struct ChunkPool<T, const N: usize = { crate::DEFAULT_CHUNK_SIZE }> {
+    chunks: Vec<Chunk<T, N>>,
+    max_chunks: usize,
+    stats: PoolStats,
+}
+
+impl<T, const N: usize> ChunkPool<T, N> {
+    /// Creates an empty pool that keeps at most `max_chunks` chunks.
+    pub fn new(max_chunks: usize) -> Self {
+        ChunkPool {
+            chunks: Vec::new(),
+            max_chunks,
+            stats: PoolStats::default(),
+        }
+    }
+
+    /// Returns the maximum number of chunks the pool keeps.
+    pub fn max_chunks(&self) -> usize {
+        self.max_chunks
+    }
+
+    /// Changes the maximum number of chunks the pool keeps, freeing pooled
+    /// chunks above the new bound.
+    pub fn set_max_chunks(&mut self, max_chunks: usize) {
+        self.max_chunks = max_chunks;
+        self.chunks.truncate(max_chunks);
+        self.chunks.shrink_to(max_chunks);
+    }
+
+    /// Returns the number of chunks currently in the pool.
+    pub fn len(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// Returns `true` if the pool holds no chunks.
+    pub fn is_empty(&self) -> bool {
+        self.chunks.is_empty()
+    }
+
+    /// Returns the hit, miss, recycle and discard counters.
+    pub fn stats(&self) -> PoolStats {
+        self.stats
+    }
+
+    /// Resets all counters to zero.
+    pub fn reset_stats(&mut self) {
+        self.stats = PoolStats::default();
+    }
+
+    /// Allocates chunks until the pool holds `count` of them, or
+    /// [`max_chunks`](ChunkPool::max_chunks) if that is lower.
+    ///
+    /// These allocations are not counted as misses.
+    pub fn prefill(&mut self, count: usize) {
+        let target = count.min(self.max_chunks);
+        while self.chunks.len() < target {
+            self.chunks.push(ChunkedVec::create_empty_chunk());
+        }
+    }
+
+    /// Creates an empty vector whose chunks for `capacity` elements are
+    /// drawn from the pool.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkPool, ChunkedVec};
+    ///
+    /// let mut pool: ChunkPool<i32, 4> = ChunkPool::new(4);
+    /// pool.prefill(2);
+    /// let vec: ChunkedVec<i32, 4> = pool.vec_with_capacity(6);
+    /// assert_eq!(vec.allocated_capacity(), 8);
+    /// assert!(pool.is_empty());
+    /// ```
+    pub fn vec_with_capacity(&mut self, capacity: usize) -> ChunkedVec<T, N> {
+        let mut vec = ChunkedVecSized::new();
+        vec.reserve_from_pool(self, capacity);
+        vec
+    }
+
+    /// Drops the elements of `vec` and gives all of its chunks to the pool.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkPool, ChunkedVec, ChunkedVecSized};
+    ///
+    /// let mut pool: ChunkPool<String, 2> = ChunkPool::new(2);
+    /// let mut vec: ChunkedVec<String, 2> = ChunkedVecSized::new();
+    /// vec.extend(["a", "b", "c", "d", "e"].map(String::from));
+    ///
+    /// pool.recycle(vec);
+    /// assert_eq!(pool.len(), 2);
+    /// assert_eq!(pool.stats().discarded, 1);
+    /// ```
+    pub fn recycle(&mut self, mut vec: ChunkedVec<T, N>) {
+        vec.clear();
+        for chunk in vec.data.drain(..) {
+            self.put(chunk);
+        }
+    }
+
+    /// Takes a chunk from the pool, allocating one if the pool is empty.
+    pub(crate) fn take(&mut self) -> Chunk<T, N> {
+        match self.chunks.pop() {
+            Some(chunk) => {
+                self.stats.hits += 1;
+                chunk
+            }
+            None => {
+                self.stats.misses += 1;
+                ChunkedVec::create_empty_chunk()
+            }
+        }
+    }
+
+    /// Gives an unused chunk to the pool, freeing it if the pool is full.
+    pub(crate) fn put(&mut self, chunk: Chunk<T, N>) {
+        if self.chunks.len() < self.max_chunks {
+            self.chunks.push(chunk);
+            self.stats.recycled += 1;
+        } else {
+            self.stats.discarded += 1;
+        }
+    }
+}
+
+impl<T, const N: usize> ChunkedVec<T, N> {
+    /// Reserves storage for at least `additional` more elements, drawing the
+    /// missing chunks from `pool`.
+    ///
+    /// Behaves like [`reserve`](ChunkedVec::reserve), except that chunks
+    /// come from the pool first and are only allocated when it is empty.
+    ///
+    /// # Panics
+    /// Panics if the required length overflows `usize`.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkPool, ChunkedVec, ChunkedVecSized};
+    ///
+    /// let mut pool: ChunkPool<i32, 4> = ChunkPool::new(8);
+    /// pool.prefill(1);
+    /// let mut vec: ChunkedVec<i32, 4> = ChunkedVecSized::new();
+    /// vec.reserve_from_pool(&mut pool, 5);
+    /// assert_eq!(vec.allocated_capacity(), 8);
+    /// assert_eq!((pool.stats().hits, pool.stats().misses), (1, 1));
+    /// ```
+    pub fn reserve_from_pool(&mut self, pool: &mut ChunkPool<T, N>, additional: usize) {
+        let required_chunks = self.required_chunks(additional);
+        if required_chunks <= self.data.len() {
+            return;
+        }
+        self.data.reserve(required_chunks - self.data.len());
+        while self.data.len() < required_chunks {
+            self.data.push(pool.take());
+        }
+    }
+
+    /// Gives trailing chunks that are not needed to hold
+    /// `max(len, min_capacity)` elements to `pool`.
+    ///
+    /// This is the pooled counterpart of [`shrink_to`](ChunkedVec::shrink_to).
+    /// The chunk table is not shrunk, so the vector can grow back without
+    /// reallocating it.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkPool, ChunkedVec, ChunkedVecSized};
+    ///
+    /// let mut pool: ChunkPool<i32, 4> = ChunkPool::new(8);
+    /// let mut vec: ChunkedVec<i32, 4> = ChunkedVecSized::new();
+    /// vec.extend(0..16);
+    /// vec.truncate(3);
+    ///
+    /// vec.shrink_into_pool(&mut pool, 0);
+    /// assert_eq!(vec.allocated_capacity(), 4);
+    /// assert_eq!(pool.len(), 3);
+    /// ```
+    pub fn shrink_into_pool(&mut self, pool: &mut ChunkPool<T, N>, min_capacity: usize) {
+        let required_chunks = self.len.max(min_capacity).div_ceil(N);
+        if required_chunks >= self.data.len() {
+            return;
+        }
+        for chunk in self.data.drain(required_chunks..) {
+            pool.put(chunk);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ChunkPool, ChunkedVec, ChunkedVecSized, PoolStats};
+    use std::rc::Rc;
+
+    #[test]
+    fn test_pool_reuses_chunks() {
+        let mut pool: ChunkPool<u64, 4> = ChunkPool::new(16);
+        let mut vec = pool.vec_with_capacity(10);
+        vec.extend(0..10);
+        let chunks: Vec<*const u64> = vec.chunks().map(|c| c.as_ptr()).collect();
+        pool.recycle(vec);
+
+        // The pool hands chunks out last in, first out
+        let mut vec = pool.vec_with_capacity(10);
+        vec.extend(0..10);
+        let reused: Vec<*const u64> = vec.chunks().map(|c| c.as_ptr()).rev().collect();
+        assert_eq!(reused, chunks);
+        assert!(vec.iter().copied().eq(0..10));
+
+        assert_eq!(
+            pool.stats(),
+            PoolStats {
+                hits: 3,
+                misses: 3,
+                recycled: 3,
+                discarded: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_pool_bound() {
+        let mut pool: ChunkPool<u8, 2> = ChunkPool::new(3);
+        pool.prefill(10);
+        assert_eq!(pool.len(), 3);
+
+        let mut vec: ChunkedVec<u8, 2> = ChunkedVecSized::new();
+        vec.extend(0..10);
+        pool.recycle(vec);
+        assert_eq!(pool.len(), 3);
+        assert_eq!(pool.stats().discarded, 5);
+
+        pool.set_max_chunks(1);
+        assert_eq!(pool.len(), 1);
+        pool.reset_stats();
+        assert_eq!(pool.stats(), PoolStats::default());
+
+        let empty: ChunkPool<u8, 2> = ChunkPool::new(0);
+        assert!(empty.is_empty());
+        assert_eq!(empty.max_chunks(), 0);
+    }
+
+    #[test]
+    fn test_recycle_drops_elements() {
+        let value = Rc::new(());
+        let mut pool: ChunkPool<Rc<()>, 3> = ChunkPool::new(8);
+        let mut vec = pool.vec_with_capacity(0);
+        vec.extend((0..7).map(|_| value.clone()));
+        assert_eq!(Rc::strong_count(&value), 8);
+
+        pool.recycle(vec);
+        assert_eq!(Rc::strong_count(&value), 1);
+        assert_eq!(pool.len(), 3);
+    }
+
+    #[test]
+    fn test_shrink_into_pool_and_reserve_from_pool() {
+        let mut pool: ChunkPool<i32, 4> = ChunkPool::new(8);
+        let mut a: ChunkedVec<i32, 4> = ChunkedVecSized::new();
+        a.extend(0..20);
+        a.truncate(5);
+
+        a.shrink_into_pool(&mut pool, 9);
+        assert_eq!(a.allocated_capacity(), 12);
+        assert_eq!(pool.len(), 2);
+        a.shrink_into_pool(&mut pool, 100);
+        assert_eq!(pool.len(), 2);
+
+        let mut b: ChunkedVec<i32, 4> = ChunkedVecSized::new();
+        b.reserve_from_pool(&mut pool, 8);
+        assert_eq!(b.allocated_capacity(), 8);
+        assert!(pool.is_empty());
+        b.reserve_from_pool(&mut pool, 8);
+        assert_eq!(pool.stats().misses, 0);
+
+        b.extend(0..8);
+        a.extend(5..12);
+        assert!(a.iter().copied().eq(0..12));
+        assert!(b.iter().copied().eq(0..8));
+    }
+}
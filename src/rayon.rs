@@ -0,0 +1,1458 @@
+//! [Rayon](https://docs.rs/rayon) parallel iterators, parallel collection and
+//! parallel sorting for [`ChunkedVec`].
+//!
+//! Work is split on chunk boundaries, so every task walks whole chunks as
+//! contiguous slices. Adaptors that drive the underlying producer
+//! themselves, such as `zip`, `enumerate` or `rev`, may still split at any
+//! index; the chunk containing that index is then shared between the two
+//! halves.
+
+use std::cmp::Ordering;
+use std::collections::LinkedList;
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+use std::{mem, ptr, slice};
+
+use rayon::iter::plumbing::{
+    Consumer, Folder, Producer, ProducerCallback, Reducer, UnindexedConsumer,
+};
+use rayon::iter::{
+    FromParallelIterator, IndexedParallelIterator, IntoParallelIterator,
+    IntoParallelRefMutIterator, ParallelExtend, ParallelIterator,
+};
+use rayon::slice::ParallelSliceMut;
+
+use crate::{Chunk, ChunkedVec, ChunkedVecSized};
+
+/// Returns the elements of a full chunk.
+///
+/// # Safety
+/// All `N` slots of the chunk must be initialized.
+unsafe fn full_chunk<T, const N: usize>(chunk: &Chunk<T, N>) -> &[T] {
+    slice::from_raw_parts(chunk.as_ptr().cast(), N)
+}
+
+/// Returns the elements of a full chunk.
+///
+/// # Safety
+/// All `N` slots of the chunk must be initialized.
+unsafe fn full_chunk_mut<T, const N: usize>(chunk: &mut Chunk<T, N>) -> &mut [T] {
+    slice::from_raw_parts_mut(chunk.as_mut_ptr().cast(), N)
+}
+
+/// Picks the number of whole chunks to put in the left half when splitting
+/// a range on a chunk boundary, or `None` if the range has no boundary
+/// inside it.
+fn boundary_split(head_len: usize, body_len: usize, tail_len: usize, n: usize) -> Option<usize> {
+    let min = usize::from(head_len == 0);
+    let max = if tail_len == 0 {
+        body_len.checked_sub(1)?
+    } else {
+        body_len
+    };
+    if min > max {
+        return None;
+    }
+    let middle = (head_len + body_len * n + tail_len) / 2;
+    let chunks = (middle.saturating_sub(head_len) + n / 2) / n;
+    Some(chunks.clamp(min, max))
+}
+
+/// A range of a vector's elements: the end of the chunk it starts in, the
+/// whole chunks after that, and the start of the chunk it ends in.
+struct ChunkRuns<'a, T, const N: usize> {
+    head: &'a [T],
+    body: &'a [Chunk<T, N>],
+    tail: &'a [T],
+}
+
+impl<'a, T, const N: usize> ChunkRuns<'a, T, N> {
+    fn new(vec: &'a ChunkedVec<T, N>) -> Self {
+        let full = vec.len / N;
+        ChunkRuns {
+            head: &[],
+            body: &vec.data[..full],
+            tail: vec.chunk(full).unwrap_or(&[]),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.head.len() + self.body.len() * N + self.tail.len()
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        if index <= self.head.len() {
+            let (left, right) = self.head.split_at(index);
+            let left = ChunkRuns {
+                head: left,
+                body: &[],
+                tail: &[],
+            };
+            return (
+                left,
+                ChunkRuns {
+                    head: right,
+                    ..self
+                },
+            );
+        }
+
+        let index = index - self.head.len();
+        let (chunk_idx, offset) = (index / N, index % N);
+        if chunk_idx < self.body.len() {
+            let (left_body, right_body) = self.body.split_at(chunk_idx);
+            if offset == 0 {
+                let left = ChunkRuns {
+                    body: left_body,
+                    tail: &[],
+                    ..self
+                };
+                let right = ChunkRuns {
+                    head: &[],
+                    body: right_body,
+                    ..self
+                };
+                return (left, right);
+            }
+
+            // The split falls inside a chunk, which becomes the tail of the
+            // left half and the head of the right half.
+            let (split, right_body) = right_body.split_first().unwrap();
+            // Safety: chunks in the body are full.
+            let (left_tail, right_head) = unsafe { full_chunk(split) }.split_at(offset);
+            let left = ChunkRuns {
+                body: left_body,
+                tail: left_tail,
+                ..self
+            };
+            let right = ChunkRuns {
+                head: right_head,
+                body: right_body,
+                ..self
+            };
+            return (left, right);
+        }
+
+        let (left, right) = self.tail.split_at(index - self.body.len() * N);
+        let right = ChunkRuns {
+            head: &[],
+            body: &[],
+            tail: right,
+        };
+        (ChunkRuns { tail: left, ..self }, right)
+    }
+
+    /// Returns the chunk boundary closest to the middle of the range.
+    fn chunk_boundary(&self) -> Option<usize> {
+        let (head, body, tail) = (self.head.len(), self.body.len(), self.tail.len());
+        let chunks = boundary_split(head, body, tail, N)?;
+        Some(head + chunks * N)
+    }
+
+    fn fold_runs<F: Folder<&'a T>>(self, mut folder: F) -> F {
+        folder = folder.consume_iter(self.head);
+        for chunk in self.body {
+            if folder.full() {
+                return folder;
+            }
+            // Safety: chunks in the body are full.
+            folder = folder.consume_iter(unsafe { full_chunk(chunk) });
+        }
+        folder.consume_iter(self.tail)
+    }
+}
+
+impl<'a, T: Sync, const N: usize> Producer for ChunkRuns<'a, T, N> {
+    type Item = &'a T;
+    type IntoIter = ChunkRunsIter<'a, T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ChunkRunsIter {
+            len: self.len(),
+            front: self.head.iter(),
+            body: self.body.iter(),
+            back: self.tail.iter(),
+        }
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        self.split_at(index)
+    }
+
+    fn fold_with<F: Folder<Self::Item>>(self, folder: F) -> F {
+        self.fold_runs(folder)
+    }
+}
+
+/// The sequential iterator of one task of a [`ParIter`].
+struct ChunkRunsIter<'a, T, const N: usize> {
+    front: slice::Iter<'a, T>,
+    body: slice::Iter<'a, Chunk<T, N>>,
+    back: slice::Iter<'a, T>,
+    len: usize,
+}
+
+impl<'a, T, const N: usize> Iterator for ChunkRunsIter<'a, T, N> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        loop {
+            if let Some(elem) = self.front.next() {
+                self.len -= 1;
+                return Some(elem);
+            }
+            match self.body.next() {
+                // Safety: chunks in the body are full.
+                Some(chunk) => self.front = unsafe { full_chunk(chunk) }.iter(),
+                None => {
+                    let elem = self.back.next()?;
+                    self.len -= 1;
+                    return Some(elem);
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for ChunkRunsIter<'_, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(elem) = self.back.next_back() {
+                self.len -= 1;
+                return Some(elem);
+            }
+            match self.body.next_back() {
+                // Safety: chunks in the body are full.
+                Some(chunk) => self.back = unsafe { full_chunk(chunk) }.iter(),
+                None => {
+                    let elem = self.front.next_back()?;
+                    self.len -= 1;
+                    return Some(elem);
+                }
+            }
+        }
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for ChunkRunsIter<'_, T, N> {}
+
+impl<T, const N: usize> FusedIterator for ChunkRunsIter<'_, T, N> {}
+
+/// The mutable counterpart of [`ChunkRuns`].
+struct ChunkRunsMut<'a, T, const N: usize> {
+    head: &'a mut [T],
+    body: &'a mut [Chunk<T, N>],
+    tail: &'a mut [T],
+}
+
+impl<T, const N: usize> Default for ChunkRunsMut<'_, T, N> {
+    fn default() -> Self {
+        ChunkRunsMut {
+            head: &mut [],
+            body: &mut [],
+            tail: &mut [],
+        }
+    }
+}
+
+impl<'a, T, const N: usize> ChunkRunsMut<'a, T, N> {
+    fn new(data: &'a mut [Chunk<T, N>], len: usize) -> Self {
+        let full = len / N;
+        let (body, rest) = data[..len.div_ceil(N)].split_at_mut(full);
+        let tail = match rest.first_mut() {
+            // Safety: the first `len % N` slots of the last chunk are
+            // initialized.
+            Some(chunk) => unsafe { slice::from_raw_parts_mut(chunk.as_mut_ptr().cast(), len % N) },
+            None => &mut [],
+        };
+        ChunkRunsMut {
+            head: &mut [],
+            body,
+            tail,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.head.len() + self.body.len() * N + self.tail.len()
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let ChunkRunsMut { head, body, tail } = self;
+        if index <= head.len() {
+            let (left, right) = head.split_at_mut(index);
+            let left = ChunkRunsMut {
+                head: left,
+                ..Default::default()
+            };
+            return (
+                left,
+                ChunkRunsMut {
+                    head: right,
+                    body,
+                    tail,
+                },
+            );
+        }
+
+        let index = index - head.len();
+        let (chunk_idx, offset) = (index / N, index % N);
+        if chunk_idx < body.len() {
+            let (left_body, right_body) = body.split_at_mut(chunk_idx);
+            if offset == 0 {
+                let left = ChunkRunsMut {
+                    head,
+                    body: left_body,
+                    tail: &mut [],
+                };
+                let right = ChunkRunsMut {
+                    head: &mut [],
+                    body: right_body,
+                    tail,
+                };
+                return (left, right);
+            }
+
+            // The split falls inside a chunk, which becomes the tail of the
+            // left half and the head of the right half.
+            let (split, right_body) = right_body.split_first_mut().unwrap();
+            // Safety: chunks in the body are full.
+            let (left_tail, right_head) = unsafe { full_chunk_mut(split) }.split_at_mut(offset);
+            let left = ChunkRunsMut {
+                head,
+                body: left_body,
+                tail: left_tail,
+            };
+            let right = ChunkRunsMut {
+                head: right_head,
+                body: right_body,
+                tail,
+            };
+            return (left, right);
+        }
+
+        let (left, right) = tail.split_at_mut(index - body.len() * N);
+        let right = ChunkRunsMut {
+            tail: right,
+            ..Default::default()
+        };
+        (
+            ChunkRunsMut {
+                head,
+                body,
+                tail: left,
+            },
+            right,
+        )
+    }
+
+    /// Returns the chunk boundary closest to the middle of the range.
+    fn chunk_boundary(&self) -> Option<usize> {
+        let (head, body, tail) = (self.head.len(), self.body.len(), self.tail.len());
+        let chunks = boundary_split(head, body, tail, N)?;
+        Some(head + chunks * N)
+    }
+
+    fn into_seq_iter(self) -> ChunkRunsIterMut<'a, T, N> {
+        ChunkRunsIterMut {
+            len: self.len(),
+            front: self.head.iter_mut(),
+            body: self.body.iter_mut(),
+            back: self.tail.iter_mut(),
+        }
+    }
+
+    fn fold_runs<F: Folder<&'a mut T>>(self, mut folder: F) -> F {
+        folder = folder.consume_iter(self.head);
+        for chunk in self.body {
+            if folder.full() {
+                return folder;
+            }
+            // Safety: chunks in the body are full.
+            folder = folder.consume_iter(unsafe { full_chunk_mut(chunk) });
+        }
+        folder.consume_iter(self.tail)
+    }
+}
+
+impl<'a, T: Send, const N: usize> Producer for ChunkRunsMut<'a, T, N> {
+    type Item = &'a mut T;
+    type IntoIter = ChunkRunsIterMut<'a, T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_seq_iter()
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        self.split_at(index)
+    }
+
+    fn fold_with<F: Folder<Self::Item>>(self, folder: F) -> F {
+        self.fold_runs(folder)
+    }
+}
+
+/// The sequential iterator of one task of a [`ParIterMut`].
+struct ChunkRunsIterMut<'a, T, const N: usize> {
+    front: slice::IterMut<'a, T>,
+    body: slice::IterMut<'a, Chunk<T, N>>,
+    back: slice::IterMut<'a, T>,
+    len: usize,
+}
+
+impl<'a, T, const N: usize> Iterator for ChunkRunsIterMut<'a, T, N> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        loop {
+            if let Some(elem) = self.front.next() {
+                self.len -= 1;
+                return Some(elem);
+            }
+            match self.body.next() {
+                // Safety: chunks in the body are full.
+                Some(chunk) => self.front = unsafe { full_chunk_mut(chunk) }.iter_mut(),
+                None => {
+                    let elem = self.back.next()?;
+                    self.len -= 1;
+                    return Some(elem);
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for ChunkRunsIterMut<'_, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(elem) = self.back.next_back() {
+                self.len -= 1;
+                return Some(elem);
+            }
+            match self.body.next_back() {
+                // Safety: chunks in the body are full.
+                Some(chunk) => self.back = unsafe { full_chunk_mut(chunk) }.iter_mut(),
+                None => {
+                    let elem = self.front.next_back()?;
+                    self.len -= 1;
+                    return Some(elem);
+                }
+            }
+        }
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for ChunkRunsIterMut<'_, T, N> {}
+
+impl<T, const N: usize> FusedIterator for ChunkRunsIterMut<'_, T, N> {}
+
+/// Moves the elements of a range out of the vector. Elements that are not
+/// yielded are dropped with the producer.
+struct DrainRuns<'a, T, const N: usize> {
+    runs: ChunkRunsMut<'a, T, N>,
+}
+
+impl<T, const N: usize> Drop for DrainRuns<'_, T, N> {
+    fn drop(&mut self) {
+        let runs = mem::take(&mut self.runs);
+        // Safety: the elements of the range are initialized and owned by the
+        // producer.
+        unsafe {
+            ptr::drop_in_place(runs.head);
+            for chunk in runs.body {
+                ptr::drop_in_place(full_chunk_mut(chunk));
+            }
+            ptr::drop_in_place(runs.tail);
+        }
+    }
+}
+
+impl<'a, T: Send, const N: usize> DrainRuns<'a, T, N> {
+    fn split_at(mut self, index: usize) -> (Self, Self) {
+        let (left, right) = mem::take(&mut self.runs).split_at(index);
+        (DrainRuns { runs: left }, DrainRuns { runs: right })
+    }
+
+    fn into_seq_iter(mut self) -> DrainRunsIter<'a, T, N> {
+        DrainRunsIter {
+            iter: mem::take(&mut self.runs).into_seq_iter(),
+        }
+    }
+}
+
+impl<'a, T: Send, const N: usize> Producer for DrainRuns<'a, T, N> {
+    type Item = T;
+    type IntoIter = DrainRunsIter<'a, T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_seq_iter()
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        self.split_at(index)
+    }
+}
+
+/// The sequential iterator of one task of an [`IntoParIter`].
+struct DrainRunsIter<'a, T, const N: usize> {
+    iter: ChunkRunsIterMut<'a, T, N>,
+}
+
+impl<T, const N: usize> Drop for DrainRunsIter<'_, T, N> {
+    fn drop(&mut self) {
+        for elem in &mut self.iter {
+            // Safety: elements not yielded yet are still owned by the
+            // iterator.
+            unsafe { ptr::drop_in_place(elem) };
+        }
+    }
+}
+
+impl<T, const N: usize> Iterator for DrainRunsIter<'_, T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        // Safety: each element is yielded, and so moved out, only once.
+        self.iter.next().map(|elem| unsafe { ptr::read(elem) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for DrainRunsIter<'_, T, N> {
+    fn next_back(&mut self) -> Option<T> {
+        // Safety: each element is yielded, and so moved out, only once.
+        self.iter.next_back().map(|elem| unsafe { ptr::read(elem) })
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for DrainRunsIter<'_, T, N> {}
+
+/// A producer that can be split between two chunks.
+trait ChunkProducer: Producer {
+    /// Returns the chunk boundary closest to the middle of the range, or
+    /// `None` if there is no boundary inside it.
+    fn chunk_boundary(&self) -> Option<usize>;
+}
+
+impl<T: Sync, const N: usize> ChunkProducer for ChunkRuns<'_, T, N> {
+    fn chunk_boundary(&self) -> Option<usize> {
+        self.chunk_boundary()
+    }
+}
+
+impl<T: Send, const N: usize> ChunkProducer for ChunkRunsMut<'_, T, N> {
+    fn chunk_boundary(&self) -> Option<usize> {
+        self.chunk_boundary()
+    }
+}
+
+impl<T: Send, const N: usize> ChunkProducer for DrainRuns<'_, T, N> {
+    fn chunk_boundary(&self) -> Option<usize> {
+        self.runs.chunk_boundary()
+    }
+}
+
+/// Runs `consumer` over `producer` in parallel, splitting both on chunk
+/// boundaries.
+///
+/// This takes the place of rayon's `bridge`, which always splits at the
+/// midpoint. As there, the number of splits starts at the number of threads
+/// and is halved at every level, except that a task stolen by another thread
+/// may split again.
+fn bridge_chunks<P, C>(producer: P, consumer: C) -> C::Result
+where
+    P: ChunkProducer,
+    C: Consumer<P::Item>,
+{
+    fn helper<P, C>(producer: P, consumer: C, splits: usize, migrated: bool) -> C::Result
+    where
+        P: ChunkProducer,
+        C: Consumer<P::Item>,
+    {
+        let can_split = !consumer.full() && (splits > 0 || migrated);
+        let Some(index) = producer.chunk_boundary().filter(|_| can_split) else {
+            return producer.fold_with(consumer.into_folder()).complete();
+        };
+
+        let splits = if migrated {
+            (splits / 2).max(rayon::current_num_threads())
+        } else {
+            splits / 2
+        };
+        let (left_producer, right_producer) = producer.split_at(index);
+        let (left_consumer, right_consumer, reducer) = consumer.split_at(index);
+        let (left, right) = rayon::join_context(
+            |ctx| helper(left_producer, left_consumer, splits, ctx.migrated()),
+            |ctx| helper(right_producer, right_consumer, splits, ctx.migrated()),
+        );
+        reducer.reduce(left, right)
+    }
+
+    helper(producer, consumer, rayon::current_num_threads(), false)
+}
+
+/// A parallel iterator over references to the elements of a ChunkedVec.
+///
+/// This struct is created by `par_iter`, from rayon's
+/// [`IntoParallelRefIterator`](rayon::iter::IntoParallelRefIterator), or by
+/// calling `into_par_iter` on `&ChunkedVec`.
+pub struct ParIter<'a, T, const N: usize> {
+    vec: &'a ChunkedVec<T, N>,
+}
+
+/// A parallel iterator over mutable references to the elements of a
+/// ChunkedVec.
+///
+/// This struct is created by `par_iter_mut`, from rayon's
+/// [`IntoParallelRefMutIterator`](rayon::iter::IntoParallelRefMutIterator),
+/// or by calling `into_par_iter` on `&mut ChunkedVec`.
+pub struct ParIterMut<'a, T, const N: usize> {
+    vec: &'a mut ChunkedVec<T, N>,
+}
+
+/// A parallel iterator that moves the elements out of a ChunkedVec.
+///
+/// This struct is created by calling `into_par_iter` on a `ChunkedVec`.
+pub struct IntoParIter<T, const N: usize> {
+    vec: ChunkedVec<T, N>,
+}
+
+impl<'a, T: Sync, const N: usize> IntoParallelIterator for &'a ChunkedVec<T, N> {
+    type Iter = ParIter<'a, T, N>;
+    type Item = &'a T;
+
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkedVec, ChunkedVecSized};
+    /// use rayon::prelude::*;
+    ///
+    /// let mut vec: ChunkedVec<u64, 8> = ChunkedVecSized::new();
+    /// vec.extend(1..=100);
+    /// assert_eq!(vec.par_iter().sum::<u64>(), 5050);
+    /// ```
+    fn into_par_iter(self) -> Self::Iter {
+        ParIter { vec: self }
+    }
+}
+
+impl<'a, T: Send, const N: usize> IntoParallelIterator for &'a mut ChunkedVec<T, N> {
+    type Iter = ParIterMut<'a, T, N>;
+    type Item = &'a mut T;
+
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkedVec, ChunkedVecSized};
+    /// use rayon::prelude::*;
+    ///
+    /// let mut vec: ChunkedVec<i32, 4> = ChunkedVecSized::new();
+    /// vec.extend(0..10);
+    /// vec.par_iter_mut().for_each(|x| *x *= 2);
+    /// assert!(vec.iter().copied().eq((0..20).step_by(2)));
+    /// ```
+    fn into_par_iter(self) -> Self::Iter {
+        ParIterMut { vec: self }
+    }
+}
+
+impl<T: Send, const N: usize> IntoParallelIterator for ChunkedVec<T, N> {
+    type Iter = IntoParIter<T, N>;
+    type Item = T;
+
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkedVec, ChunkedVecSized};
+    /// use rayon::prelude::*;
+    ///
+    /// let mut vec: ChunkedVec<String, 4> = ChunkedVecSized::new();
+    /// vec.extend((0..10).map(|i| i.to_string()));
+    /// let lengths: Vec<usize> = vec.into_par_iter().map(|s| s.len()).collect();
+    /// assert_eq!(lengths, [1; 10]);
+    /// ```
+    fn into_par_iter(self) -> Self::Iter {
+        IntoParIter { vec: self }
+    }
+}
+
+impl<'a, T: Sync, const N: usize> ParallelIterator for ParIter<'a, T, N> {
+    type Item = &'a T;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        self.drive(consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.vec.len)
+    }
+}
+
+impl<T: Sync, const N: usize> IndexedParallelIterator for ParIter<'_, T, N> {
+    fn len(&self) -> usize {
+        self.vec.len
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge_chunks(ChunkRuns::new(self.vec), consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        callback.callback(ChunkRuns::new(self.vec))
+    }
+}
+
+impl<'a, T: Send, const N: usize> ParallelIterator for ParIterMut<'a, T, N> {
+    type Item = &'a mut T;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        self.drive(consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.vec.len)
+    }
+}
+
+impl<T: Send, const N: usize> IndexedParallelIterator for ParIterMut<'_, T, N> {
+    fn len(&self) -> usize {
+        self.vec.len
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge_chunks(
+            ChunkRunsMut::new(&mut self.vec.data, self.vec.len),
+            consumer,
+        )
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        callback.callback(ChunkRunsMut::new(&mut self.vec.data, self.vec.len))
+    }
+}
+
+impl<T: Send, const N: usize> IntoParIter<T, N> {
+    /// Hands the elements to a producer, which from then on owns them. The
+    /// vector keeps its chunks and frees them when dropped.
+    fn drain(&mut self) -> DrainRuns<'_, T, N> {
+        // The elements are moved out or dropped by the producer
+        let len = mem::replace(&mut self.vec.len, 0);
+        DrainRuns {
+            runs: ChunkRunsMut::new(&mut self.vec.data, len),
+        }
+    }
+}
+
+impl<T: Send, const N: usize> ParallelIterator for IntoParIter<T, N> {
+    type Item = T;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        self.drive(consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.vec.len)
+    }
+}
+
+impl<T: Send, const N: usize> IndexedParallelIterator for IntoParIter<T, N> {
+    fn len(&self) -> usize {
+        self.vec.len
+    }
+
+    fn drive<C: Consumer<Self::Item>>(mut self, consumer: C) -> C::Result {
+        bridge_chunks(self.drain(), consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(mut self, callback: CB) -> CB::Output {
+        callback.callback(self.drain())
+    }
+}
+
+/// Returns a pointer to the slot at logical `index` of a chunk table,
+/// without borrowing the chunk it is in.
+///
+/// # Safety
+/// The chunk holding `index` must be in the table.
+unsafe fn slot_ptr<T, const N: usize>(table: *mut Chunk<T, N>, index: usize) -> *mut T {
+    ptr::addr_of_mut!(**table.add(index / N))
+        .cast::<T>()
+        .add(index % N)
+}
+
+/// Moves the items of an indexed parallel iterator straight into their
+/// slots in preallocated chunks, like rayon's own collect consumer does for
+/// `Vec`.
+///
+/// Tasks write disjoint ranges of slots, possibly sharing the chunk a range
+/// boundary falls in, so the chunks are reached through a raw pointer to the
+/// chunk table rather than through borrows of them.
+struct CollectConsumer<'c, T, const N: usize> {
+    table: *mut Chunk<T, N>,
+    /// Logical index of the first slot to write.
+    start: usize,
+    /// Number of slots to write.
+    len: usize,
+    _marker: PhantomData<&'c mut T>,
+}
+
+// Safety: each consumer moves items into its own range of slots only.
+unsafe impl<T: Send, const N: usize> Send for CollectConsumer<'_, T, N> {}
+
+/// The slots a [`CollectConsumer`] has written so far. Dropping it drops the
+/// elements written, so a panicking task does not leak them.
+struct CollectResult<'c, T, const N: usize> {
+    table: *mut Chunk<T, N>,
+    start: usize,
+    /// Number of slots this result may write.
+    len: usize,
+    /// Number of slots written, starting at `start`.
+    written: usize,
+    _marker: PhantomData<&'c mut T>,
+}
+
+// Safety: see `CollectConsumer`.
+unsafe impl<T: Send, const N: usize> Send for CollectResult<'_, T, N> {}
+
+impl<T, const N: usize> Drop for CollectResult<'_, T, N> {
+    fn drop(&mut self) {
+        for index in self.start..self.start + self.written {
+            // Safety: the slot was written by this result and is not counted
+            // by the vector.
+            unsafe { ptr::drop_in_place(slot_ptr(self.table, index)) };
+        }
+    }
+}
+
+impl<'c, T: Send, const N: usize> Consumer<T> for CollectConsumer<'c, T, N> {
+    type Folder = CollectResult<'c, T, N>;
+    type Reducer = CollectReducer;
+    type Result = CollectResult<'c, T, N>;
+
+    fn split_at(self, index: usize) -> (Self, Self, Self::Reducer) {
+        assert!(index <= self.len, "split index out of range");
+        let right = CollectConsumer {
+            table: self.table,
+            start: self.start + index,
+            len: self.len - index,
+            _marker: PhantomData,
+        };
+        let left = CollectConsumer { len: index, ..self };
+        (left, right, CollectReducer)
+    }
+
+    fn into_folder(self) -> Self::Folder {
+        CollectResult {
+            table: self.table,
+            start: self.start,
+            len: self.len,
+            written: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    fn full(&self) -> bool {
+        false
+    }
+}
+
+/// Only used by iterators that report their length, which must drive the
+/// consumer through indexed splits.
+impl<T: Send, const N: usize> UnindexedConsumer<T> for CollectConsumer<'_, T, N> {
+    fn split_off_left(&self) -> Self {
+        unreachable!("an iterator with a known length must split its consumer by index")
+    }
+
+    fn to_reducer(&self) -> Self::Reducer {
+        CollectReducer
+    }
+}
+
+impl<T: Send, const N: usize> Folder<T> for CollectResult<'_, T, N> {
+    type Result = Self;
+
+    fn consume(mut self, item: T) -> Self {
+        assert!(
+            self.written < self.len,
+            "too many values pushed to consumer"
+        );
+        // Safety: the slot is allocated, belongs to this result and has not
+        // been written yet.
+        unsafe { slot_ptr(self.table, self.start + self.written).write(item) };
+        self.written += 1;
+        self
+    }
+
+    fn complete(self) -> Self {
+        self
+    }
+
+    fn full(&self) -> bool {
+        false
+    }
+}
+
+/// Joins the results of two adjacent tasks.
+struct CollectReducer;
+
+impl<'c, T, const N: usize> Reducer<CollectResult<'c, T, N>> for CollectReducer {
+    fn reduce(
+        self,
+        mut left: CollectResult<'c, T, N>,
+        right: CollectResult<'c, T, N>,
+    ) -> CollectResult<'c, T, N> {
+        // Only a left half that was written completely continues into the
+        // right half. Otherwise the right half is dropped, and the total
+        // comes up short.
+        if left.start + left.written == right.start {
+            left.len += right.len;
+            left.written += right.written;
+            mem::forget(right);
+        }
+        left
+    }
+}
+
+impl<T: Send, const N: usize> ChunkedVec<T, N> {
+    /// Moves the `len` items of `par_iter` into chunks allocated up front,
+    /// each one straight into its final slot.
+    fn par_extend_exact<I>(&mut self, par_iter: I, len: usize)
+    where
+        I: ParallelIterator<Item = T>,
+    {
+        self.allocate_chunks_for(len);
+        let consumer = CollectConsumer {
+            table: self.data.as_mut_ptr(),
+            start: self.len,
+            len,
+            _marker: PhantomData,
+        };
+        let result = par_iter.drive_unindexed(consumer);
+
+        let written = result.written;
+        assert!(
+            written == len,
+            "expected {len} total writes, but got {written}"
+        );
+        mem::forget(result);
+        self.len += len;
+    }
+}
+
+/// Extends the vector from a parallel iterator.
+///
+/// If the iterator reports its length, the chunks are allocated up front and
+/// every task moves its items straight into their slots, so whole chunks are
+/// filled in place and nothing is copied afterwards.
+///
+/// Otherwise, every task fills a vector of its own, chunk by chunk. These
+/// are then appended in order, which moves whole chunks into the chunk table
+/// when the length so far is a multiple of `N`, and copies each chunk with
+/// one `memcpy` otherwise.
+///
+/// # Examples
+/// ```
+/// use chunked_vec::{ChunkedVec, ChunkedVecSized};
+/// use rayon::prelude::*;
+///
+/// let mut vec: ChunkedVec<u32, 16> = ChunkedVecSized::new();
+/// vec.par_extend((0..1000).into_par_iter().map(|x| x * 2));
+/// vec.par_extend((0..1000).into_par_iter().filter(|x| x % 3 == 0));
+/// assert!(vec.iter().take(1000).copied().eq((0..2000).step_by(2)));
+/// assert!(vec.iter().skip(1000).copied().eq((0..1000).step_by(3)));
+/// ```
+impl<T: Send, const N: usize> ParallelExtend<T> for ChunkedVec<T, N> {
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = T>,
+    {
+        let par_iter = par_iter.into_par_iter();
+        if let Some(len) = par_iter.opt_len() {
+            self.par_extend_exact(par_iter, len);
+            return;
+        }
+
+        let pieces: LinkedList<ChunkedVec<T, N>> = par_iter
+            .fold(ChunkedVecSized::new, |mut vec, elem| {
+                vec.push(elem);
+                vec
+            })
+            .map(|vec| LinkedList::from([vec]))
+            .reduce(LinkedList::new, |mut left, mut right| {
+                left.append(&mut right);
+                left
+            });
+
+        for mut piece in pieces {
+            self.append(&mut piece);
+        }
+    }
+}
+
+/// Extends the vector by copying the elements of a parallel iterator over
+/// references.
+impl<'a, T, const N: usize> ParallelExtend<&'a T> for ChunkedVec<T, N>
+where
+    T: Copy + Send + Sync + 'a,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = &'a T>,
+    {
+        self.par_extend(par_iter.into_par_iter().copied());
+    }
+}
+
+/// Collects a parallel iterator into a ChunkedVec, keeping the order of the
+/// elements.
+///
+/// # Examples
+/// ```
+/// use chunked_vec::ChunkedVec;
+/// use rayon::prelude::*;
+///
+/// let vec: ChunkedVec<u64> = (0..500u64).into_par_iter().map(|x| x * x).collect();
+/// assert_eq!(vec.len(), 500);
+/// assert_eq!(vec[499], 499 * 499);
+/// ```
+impl<T: Send, const N: usize> FromParallelIterator<T> for ChunkedVec<T, N> {
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = T>,
+    {
+        let mut vec = ChunkedVecSized::new();
+        vec.par_extend(par_iter);
+        vec
+    }
+}
+
+/// Implementation of parallel sorting for ChunkedVec.
+///
+/// Each chunk is sorted on its own, in parallel. Sorted runs are then merged
+/// pairwise, doubling in length each round, between the vector's chunks and
+/// a second set of chunks of the same size. The merges of one round run in
+/// parallel.
+///
+/// A merge only reads from the chunks it merges, so if a comparison panics,
+/// the vector is left holding every element, in an unspecified order.
+impl<T: Send, const N: usize> ChunkedVec<T, N> {
+    /// Sorts the vector in parallel, but might not preserve the order of
+    /// equal elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkedVec, ChunkedVecSized};
+    ///
+    /// let mut vec: ChunkedVec<i32, 4> = ChunkedVecSized::new();
+    /// vec.extend([5, 4, 1, 3, 2, 9, 8, 7, 6, 0]);
+    /// vec.par_sort_unstable();
+    /// assert_eq!(vec, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    /// ```
+    pub fn par_sort_unstable(&mut self)
+    where
+        T: Ord,
+    {
+        self.par_sort_unstable_by(T::cmp);
+    }
+
+    /// Sorts the vector in parallel with a comparator function, but might
+    /// not preserve the order of equal elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkedVec, ChunkedVecSized};
+    ///
+    /// let mut vec: ChunkedVec<i32, 4> = ChunkedVecSized::new();
+    /// vec.extend([5, 4, 1, 3, 2]);
+    /// vec.par_sort_unstable_by(|a, b| b.cmp(a));
+    /// assert_eq!(vec, [5, 4, 3, 2, 1]);
+    /// ```
+    pub fn par_sort_unstable_by<F>(&mut self, compare: F)
+    where
+        F: Fn(&T, &T) -> Ordering + Sync,
+    {
+        let len = self.len;
+        let chunk_count = len.div_ceil(N);
+        let chunks = &mut self.data[..chunk_count];
+        chunks
+            .par_iter_mut()
+            .enumerate()
+            .for_each(|(chunk_idx, chunk)| {
+                let chunk_len = (len - chunk_idx * N).min(N);
+                // Safety: the first `chunk_len` slots of the chunk are
+                // initialized.
+                let elems =
+                    unsafe { slice::from_raw_parts_mut(chunk.as_mut_ptr().cast(), chunk_len) };
+                elems.sort_unstable_by(&compare);
+            });
+        if chunk_count <= 1 {
+            return;
+        }
+
+        // The scratch chunks only ever hold bitwise copies, so dropping them
+        // drops no element.
+        let mut scratch: Vec<Chunk<T, N>> = (0..chunk_count)
+            .map(|_| Self::create_empty_chunk())
+            .collect();
+        let mut width = 1;
+        while width < chunk_count {
+            let group = 2 * width;
+            self.data[..chunk_count]
+                .par_chunks_mut(group)
+                .zip(scratch.par_chunks_mut(group))
+                .enumerate()
+                .for_each(|(group_idx, (src, dst))| {
+                    let group_len = (len - group_idx * group * N).min(group * N);
+                    let mid = (width * N).min(group_len);
+                    // Safety: `src` holds two sorted runs, `0..mid` and
+                    // `mid..group_len`, and `dst` has as many slots.
+                    unsafe { merge_runs(src, dst, mid, group_len, &compare) };
+                });
+            // The merged runs become the vector's chunks
+            self.data[..chunk_count].swap_with_slice(&mut scratch);
+            width = group;
+        }
+    }
+
+    /// Sorts the vector in parallel with a key extraction function, but
+    /// might not preserve the order of equal elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkedVec, ChunkedVecSized};
+    ///
+    /// let mut vec: ChunkedVec<i32, 4> = ChunkedVecSized::new();
+    /// vec.extend([-5, 4, 1, -3, 2]);
+    /// vec.par_sort_unstable_by_key(|x| x.abs());
+    /// assert_eq!(vec, [1, 2, -3, 4, -5]);
+    /// ```
+    pub fn par_sort_unstable_by_key<K, F>(&mut self, f: F)
+    where
+        K: Ord,
+        F: Fn(&T) -> K + Sync,
+    {
+        self.par_sort_unstable_by(|a, b| f(a).cmp(&f(b)));
+    }
+}
+
+/// Returns a pointer to the element at `index` of a group of chunks.
+///
+/// # Safety
+/// `index` must lie within the chunks.
+unsafe fn elem_ptr<T, const N: usize>(chunks: &[Chunk<T, N>], index: usize) -> *const T {
+    chunks[index / N].as_ptr().add(index % N).cast()
+}
+
+/// Returns a pointer to the element at `index` of a group of chunks.
+///
+/// # Safety
+/// `index` must lie within the chunks.
+unsafe fn elem_mut_ptr<T, const N: usize>(chunks: &mut [Chunk<T, N>], index: usize) -> *mut T {
+    chunks[index / N].as_mut_ptr().add(index % N).cast()
+}
+
+/// Copies `count` elements from `src[from..]` to `dst[to..]`, one chunk run
+/// at a time.
+///
+/// # Safety
+/// Both ranges must lie within their chunks, and the source range must be
+/// initialized.
+unsafe fn copy_elems<T, const N: usize>(
+    src: &[Chunk<T, N>],
+    mut from: usize,
+    dst: &mut [Chunk<T, N>],
+    mut to: usize,
+    mut count: usize,
+) {
+    while count > 0 {
+        let run = count.min(N - from % N).min(N - to % N);
+        ptr::copy_nonoverlapping(elem_ptr(src, from), elem_mut_ptr(dst, to), run);
+        from += run;
+        to += run;
+        count -= run;
+    }
+}
+
+/// Merges the sorted runs `src[..mid]` and `src[mid..len]` into `dst[..len]`
+/// by bitwise copies, leaving `src` untouched.
+///
+/// # Safety
+/// `src[..len]` must be initialized, and `dst` must have at least `len`
+/// slots.
+unsafe fn merge_runs<T, const N: usize, F>(
+    src: &[Chunk<T, N>],
+    dst: &mut [Chunk<T, N>],
+    mid: usize,
+    len: usize,
+    compare: &F,
+) where
+    F: Fn(&T, &T) -> Ordering,
+{
+    let (mut left, mut right, mut out) = (0, mid, 0);
+    while left < mid && right < len {
+        let (a, b) = (elem_ptr(src, left), elem_ptr(src, right));
+        // Take from the left run on ties
+        let from = if compare(&*b, &*a) == Ordering::Less {
+            right += 1;
+            b
+        } else {
+            left += 1;
+            a
+        };
+        ptr::copy_nonoverlapping(from, elem_mut_ptr(dst, out), 1);
+        out += 1;
+    }
+    copy_elems(src, left, dst, out, mid - left);
+    out += mid - left;
+    copy_elems(src, right, dst, out, len - right);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ChunkedVec, ChunkedVecSized};
+    use rayon::prelude::*;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn vec_of<const N: usize>(len: usize) -> ChunkedVec<usize, N> {
+        let mut vec = ChunkedVecSized::new();
+        vec.extend(0..len);
+        vec
+    }
+
+    #[test]
+    fn test_par_iter_matches_iter() {
+        for len in [0, 1, 3, 4, 5, 17, 1000] {
+            let vec = vec_of::<4>(len);
+            let collected: Vec<&usize> = vec.par_iter().collect();
+            assert!(collected.into_iter().eq(vec.iter()));
+            assert_eq!(vec.par_iter().sum::<usize>(), vec.iter().sum());
+            assert_eq!(vec.par_iter().len(), len);
+
+            // Indexed adaptors split inside chunks
+            let reversed: Vec<usize> = vec.par_iter().rev().copied().collect();
+            assert!(reversed.into_iter().eq((0..len).rev()));
+            let pairs: Vec<(usize, usize)> = vec
+                .par_iter()
+                .zip(vec.par_iter().skip(1))
+                .map(|(a, b)| (*a, *b))
+                .collect();
+            assert!(pairs.into_iter().eq((0..len).zip(1..len)));
+            assert!(vec
+                .par_iter()
+                .enumerate()
+                .with_max_len(3)
+                .all(|(i, x)| i == *x));
+        }
+    }
+
+    #[test]
+    fn test_par_iter_mut() {
+        for len in [0, 5, 1001] {
+            let mut vec = vec_of::<8>(len);
+            vec.par_iter_mut().for_each(|x| *x += 1);
+            assert!(vec.iter().copied().eq(1..=len));
+
+            vec.par_iter_mut()
+                .enumerate()
+                .with_max_len(5)
+                .for_each(|(i, x)| *x = i * 2);
+            assert!(vec.iter().copied().eq((0..len).map(|i| i * 2)));
+        }
+    }
+
+    #[test]
+    fn test_into_par_iter_moves_and_drops() {
+        for len in [0, 9, 300] {
+            let mut vec: ChunkedVec<String, 4> = ChunkedVecSized::new();
+            vec.extend((0..len).map(|i| i.to_string()));
+            let strings: Vec<String> = vec.into_par_iter().collect();
+            let expected: Vec<String> = (0..len).map(|i| i.to_string()).collect();
+            assert_eq!(strings, expected);
+        }
+
+        // Elements that are never consumed are dropped
+        let counter = AtomicUsize::new(0);
+        struct Counted<'a>(&'a AtomicUsize);
+        impl Drop for Counted<'_> {
+            fn drop(&mut self) {
+                self.0.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+        let mut vec: ChunkedVec<Counted, 3> = ChunkedVecSized::new();
+        vec.extend((0..50).map(|_| Counted(&counter)));
+        let taken = vec.into_par_iter().take(7).count();
+        assert_eq!(taken, 7);
+        assert_eq!(counter.load(Ordering::Relaxed), 50);
+    }
+
+    #[test]
+    fn test_chunk_boundary_splits() {
+        // Every task of an unindexed traversal sees whole chunks, except the
+        // last, partial one.
+        let vec = vec_of::<16>(16 * 40 + 5);
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(4)
+            .build()
+            .unwrap();
+        let starts: Vec<usize> = pool.install(|| {
+            vec.par_iter()
+                .fold(Vec::new, |mut starts: Vec<usize>, x| {
+                    if starts.is_empty() {
+                        starts.push(*x);
+                    }
+                    starts
+                })
+                .flatten()
+                .collect()
+        });
+        assert!(starts.len() > 1);
+        assert!(starts.iter().all(|start| start % 16 == 0));
+    }
+
+    #[test]
+    fn test_par_extend_and_collect() {
+        let mut vec = vec_of::<4>(3);
+        vec.par_extend((3..1000).into_par_iter());
+        assert!(vec.iter().copied().eq(0..1000));
+
+        let source: Vec<usize> = (1000..1010).collect();
+        vec.par_extend(source.par_iter());
+        assert!(vec.iter().copied().eq(0..1010));
+
+        let collected: ChunkedVec<usize, 8> = (0..777).into_par_iter().rev().collect();
+        assert!(collected.iter().copied().eq((0..777).rev()));
+        let empty: ChunkedVec<usize, 8> = (0..0).into_par_iter().collect();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_par_extend_fills_chunks_in_place() {
+        let mut vec = vec_of::<4>(3);
+        vec.reserve(1000);
+        let chunks: Vec<*const _> = vec.data.iter().map(|chunk| chunk.as_ptr()).collect();
+
+        vec.par_extend((3..1003).into_par_iter().map(|x| x.to_string().len()));
+        assert_eq!(vec.len(), 1003);
+        assert!(vec
+            .iter()
+            .skip(3)
+            .copied()
+            .eq((3..1003).map(|x| x.to_string().len())));
+        // No chunk was reallocated or copied
+        assert_eq!(vec.data.len(), chunks.len());
+        assert!(vec.data.iter().map(|chunk| chunk.as_ptr()).eq(chunks));
+    }
+
+    #[test]
+    fn test_par_extend_panic_drops_written() {
+        let created = AtomicUsize::new(0);
+        let dropped = AtomicUsize::new(0);
+        struct Counted<'a>(&'a AtomicUsize);
+        impl Drop for Counted<'_> {
+            fn drop(&mut self) {
+                self.0.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+        let counted = || {
+            created.fetch_add(1, Ordering::Relaxed);
+            Counted(&dropped)
+        };
+
+        let mut vec: ChunkedVec<Counted, 4> = ChunkedVecSized::new();
+        vec.push(counted());
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            vec.par_extend((0..500).into_par_iter().map(|i| {
+                if i == 321 {
+                    panic!("item failed");
+                }
+                counted()
+            }));
+        }));
+        assert!(result.is_err());
+        assert_eq!(vec.len(), 1);
+        // Every item written before the panic was dropped again
+        assert_eq!(
+            dropped.load(Ordering::Relaxed),
+            created.load(Ordering::Relaxed) - 1
+        );
+        drop(vec);
+        assert_eq!(
+            dropped.load(Ordering::Relaxed),
+            created.load(Ordering::Relaxed)
+        );
+    }
+
+    #[test]
+    fn test_par_sort_unstable() {
+        for len in [0, 1, 7, 8, 9, 100, 5000] {
+            let mut vec: ChunkedVec<u64, 8> = ChunkedVecSized::new();
+            let mut state = len as u64 + 1;
+            vec.extend((0..len).map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state % 100
+            }));
+            let mut expected: Vec<u64> = vec.iter().copied().collect();
+            expected.sort_unstable();
+
+            vec.par_sort_unstable();
+            assert_eq!(vec, expected);
+
+            vec.par_sort_unstable_by(|a, b| b.cmp(a));
+            expected.reverse();
+            assert_eq!(vec, expected);
+
+            vec.par_sort_unstable_by_key(|x| *x);
+            expected.reverse();
+            assert_eq!(vec, expected);
+        }
+    }
+
+    #[test]
+    fn test_par_sort_unstable_keeps_retained_chunks() {
+        let mut vec = vec_of::<4>(40);
+        vec.truncate(10);
+        vec.par_sort_unstable_by(|a, b| b.cmp(a));
+        assert_eq!(vec, [9, 8, 7, 6, 5, 4, 3, 2, 1, 0]);
+        assert_eq!(vec.allocated_capacity(), 40);
+    }
+
+    #[test]
+    fn test_par_sort_unstable_panic_keeps_elements() {
+        let mut vec: ChunkedVec<String, 4> = ChunkedVecSized::new();
+        vec.extend((0..100).rev().map(|i| i.to_string()));
+        let calls = AtomicUsize::new(0);
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            vec.par_sort_unstable_by(|a, b| {
+                if calls.fetch_add(1, Ordering::Relaxed) == 400 {
+                    panic!("comparison failed");
+                }
+                a.cmp(b)
+            });
+        }));
+        assert!(result.is_err());
+
+        let mut elems: Vec<String> = vec.into_iter().collect();
+        elems.sort_by_key(|s| s.parse::<u32>().unwrap());
+        let expected: Vec<String> = (0..100).map(|i| i.to_string()).collect();
+        assert_eq!(elems, expected);
+    }
+}
@@ -0,0 +1,416 @@
+use crate::ChunkedVec;
+use std::ptr;
+
+/// Implementation of element reordering and order checks for ChunkedVec.
+///
+/// Reversing and rotating work on runs of elements that stay within one
+/// chunk on both sides, so elements are moved with block swaps instead of
+/// one logical index at a time. Neither operation allocates.
+impl<T, const N: usize> ChunkedVec<T, N> {
+    /// Swaps two elements in the vector.
+    ///
+    /// If `a` equals `b`, it's guaranteed that elements won't change value.
+    ///
+    /// # Panics
+    /// Panics if `a` or `b` are out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkedVec, ChunkedVecSized};
+    /// let mut vec: ChunkedVec<char, 2> = ChunkedVecSized::new();
+    /// vec.extend(['a', 'b', 'c', 'd', 'e']);
+    /// vec.swap(2, 4);
+    /// assert_eq!(vec, ['a', 'b', 'e', 'd', 'c']);
+    /// ```
+    pub fn swap(&mut self, a: usize, b: usize) {
+        for index in [a, b] {
+            if index >= self.len {
+                panic!(
+                    "Index out of bounds: index {} >= length {}",
+                    index, self.len
+                );
+            }
+        }
+        // Safety: both indices were checked above.
+        unsafe { self.swap_unchecked(a, b) };
+    }
+
+    /// Reverses the order of elements in the vector, in place.
+    ///
+    /// Elements are exchanged between mirrored runs at the front and back
+    /// of the vector, one pair of chunk slices at a time.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkedVec, ChunkedVecSized};
+    /// let mut vec: ChunkedVec<i32, 2> = ChunkedVecSized::new();
+    /// vec.extend([1, 2, 3, 4, 5]);
+    /// vec.reverse();
+    /// assert_eq!(vec, [5, 4, 3, 2, 1]);
+    /// ```
+    pub fn reverse(&mut self) {
+        self.reverse_range(0, self.len);
+    }
+
+    /// Rotates the vector in-place such that the first `mid` elements move
+    /// to the end while the last `self.len() - mid` elements move to the
+    /// front.
+    ///
+    /// After calling `rotate_left`, the element previously at index `mid`
+    /// becomes the first element in the vector.
+    ///
+    /// # Panics
+    /// This function will panic if `mid` is greater than the length of the
+    /// vector. Note that `mid == self.len()` does _not_ panic and is a no-op
+    /// rotation.
+    ///
+    /// # Complexity
+    /// Takes linear (in `self.len()`) time and does not allocate.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkedVec, ChunkedVecSized};
+    /// let mut vec: ChunkedVec<char, 4> = ChunkedVecSized::new();
+    /// vec.extend(['a', 'b', 'c', 'd', 'e', 'f']);
+    /// vec.rotate_left(2);
+    /// assert_eq!(vec, ['c', 'd', 'e', 'f', 'a', 'b']);
+    /// ```
+    pub fn rotate_left(&mut self, mid: usize) {
+        assert!(mid <= self.len, "assertion failed: mid <= self.len()");
+        self.rotate_range(0, mid, self.len);
+    }
+
+    /// Rotates the vector in-place such that the first `self.len() - k`
+    /// elements move to the end while the last `k` elements move to the
+    /// front.
+    ///
+    /// After calling `rotate_right`, the element previously at index
+    /// `self.len() - k` becomes the first element in the vector.
+    ///
+    /// # Panics
+    /// This function will panic if `k` is greater than the length of the
+    /// vector. Note that `k == self.len()` does _not_ panic and is a no-op
+    /// rotation.
+    ///
+    /// # Complexity
+    /// Takes linear (in `self.len()`) time and does not allocate.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkedVec, ChunkedVecSized};
+    /// let mut vec: ChunkedVec<char, 4> = ChunkedVecSized::new();
+    /// vec.extend(['a', 'b', 'c', 'd', 'e', 'f']);
+    /// vec.rotate_right(2);
+    /// assert_eq!(vec, ['e', 'f', 'a', 'b', 'c', 'd']);
+    /// ```
+    pub fn rotate_right(&mut self, k: usize) {
+        assert!(k <= self.len, "assertion failed: k <= self.len()");
+        self.rotate_range(0, self.len - k, self.len);
+    }
+
+    /// Checks if the elements of this vector are sorted.
+    ///
+    /// That is, for each element `a` and its following element `b`, `a <= b`
+    /// must hold. If the vector yields exactly zero or one element, `true`
+    /// is returned.
+    ///
+    /// Note that if `T` is only `PartialOrd`, but not `Ord`, the above
+    /// definition implies that this function returns `false` if any two
+    /// consecutive items are not comparable.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkedVec, ChunkedVecSized};
+    /// let mut vec: ChunkedVec<f64, 2> = ChunkedVecSized::new();
+    /// vec.extend([1.0, 2.0, 2.0, 9.0]);
+    /// assert!(vec.is_sorted());
+    ///
+    /// vec.push(f64::NAN);
+    /// assert!(!vec.is_sorted());
+    /// ```
+    pub fn is_sorted(&self) -> bool
+    where
+        T: PartialOrd,
+    {
+        self.is_sorted_by(|a, b| a <= b)
+    }
+
+    /// Checks if the elements of this vector are sorted using the given
+    /// comparator function.
+    ///
+    /// Instead of using `PartialOrd::partial_cmp`, this function uses the
+    /// given `compare` function to determine whether two elements are to be
+    /// considered in sorted order.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkedVec, ChunkedVecSized};
+    /// let mut vec: ChunkedVec<i32, 2> = ChunkedVecSized::new();
+    /// vec.extend([1, 2, 2, 9]);
+    /// assert!(vec.is_sorted_by(|a, b| a <= b));
+    /// assert!(!vec.is_sorted_by(|a, b| a < b));
+    /// ```
+    pub fn is_sorted_by<F>(&self, mut compare: F) -> bool
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        let mut prev: Option<&T> = None;
+        for chunk in self.chunks() {
+            // Chunk slices are never empty.
+            if let Some(prev) = prev {
+                if !compare(prev, &chunk[0]) {
+                    return false;
+                }
+            }
+            if !chunk.windows(2).all(|w| compare(&w[0], &w[1])) {
+                return false;
+            }
+            prev = chunk.last();
+        }
+        true
+    }
+
+    /// Checks if the elements of this vector are sorted using the given key
+    /// extraction function.
+    ///
+    /// Instead of comparing the vector's elements directly, this function
+    /// compares the keys of the elements, as determined by `f`. Apart from
+    /// that, it's equivalent to [`is_sorted`]; see its documentation for
+    /// more information.
+    ///
+    /// [`is_sorted`]: ChunkedVec::is_sorted
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkedVec, ChunkedVecSized};
+    /// let mut vec: ChunkedVec<&str, 2> = ChunkedVecSized::new();
+    /// vec.extend(["c", "bb", "aaa"]);
+    /// assert!(vec.is_sorted_by_key(|s| s.len()));
+    /// assert!(!vec.is_sorted());
+    /// ```
+    pub fn is_sorted_by_key<K, F>(&self, mut f: F) -> bool
+    where
+        F: FnMut(&T) -> K,
+        K: PartialOrd,
+    {
+        self.is_sorted_by(|a, b| f(a) <= f(b))
+    }
+
+    /// Reverses the order of the elements in the logical range `[lo, hi)`.
+    pub(crate) fn reverse_range(&mut self, mut lo: usize, mut hi: usize) {
+        debug_assert!(lo <= hi && hi <= self.len);
+        while hi - lo >= 2 {
+            let (front_chunk, front_off) = self.chunk_and_offset(lo);
+            let (back_chunk, back_last) = self.chunk_and_offset(hi - 1);
+            if front_chunk == back_chunk {
+                // Safety: the remaining range is initialized and lies within
+                // one chunk.
+                unsafe {
+                    std::slice::from_raw_parts_mut(
+                        self.get_elem_mut_ptr(front_chunk, front_off),
+                        hi - lo,
+                    )
+                }
+                .reverse();
+                return;
+            }
+
+            // Runs in different chunks never overlap.
+            let run = (N - front_off).min(back_last + 1);
+            // Safety: both runs are initialized, lie within their own chunks
+            // and are exchanged element by element in mirrored order.
+            unsafe {
+                let front = self.get_elem_mut_ptr(front_chunk, front_off);
+                let back = self.get_elem_mut_ptr(back_chunk, back_last);
+                for i in 0..run {
+                    ptr::swap_nonoverlapping(front.add(i), back.sub(i), 1);
+                }
+            }
+            lo += run;
+            hi -= run;
+        }
+    }
+
+    /// Moves the elements of `[mid, hi)` in front of those of `[lo, mid)`.
+    ///
+    /// Uses the Gries-Mills block swap: the shorter side is swapped into
+    /// its final place and the rotation continues on what is left, so every
+    /// element is moved by block swaps of chunk runs.
+    pub(crate) fn rotate_range(&mut self, lo: usize, mid: usize, hi: usize) {
+        debug_assert!(lo <= mid && mid <= hi && hi <= self.len);
+        if lo == mid || mid == hi {
+            return;
+        }
+        let (mut left, mut right) = (mid - lo, hi - mid);
+        while left != right {
+            if left > right {
+                // The right side lands at the start of what is left
+                self.swap_ranges(mid - left, mid, right);
+                left -= right;
+            } else {
+                // The left side lands at the end of what is left
+                self.swap_ranges(mid - left, mid + right - left, left);
+                right -= left;
+            }
+        }
+        self.swap_ranges(mid - left, mid, left);
+    }
+
+    /// Swaps the `count` elements starting at `a` with the `count` elements
+    /// starting at `b`, one chunk run at a time.
+    ///
+    /// The two ranges must not overlap and must lie within `self.len`.
+    fn swap_ranges(&mut self, mut a: usize, mut b: usize, mut count: usize) {
+        debug_assert!(a + count <= b || b + count <= a);
+        debug_assert!(a.max(b) + count <= self.len);
+        while count > 0 {
+            let (a_chunk, a_off) = self.chunk_and_offset(a);
+            let (b_chunk, b_off) = self.chunk_and_offset(b);
+            let run = (N - a_off).min(N - b_off).min(count);
+            // Safety: both runs are initialized and do not overlap. Same-chunk
+            // pointers are derived from one borrow of the chunk.
+            unsafe {
+                if a_chunk == b_chunk {
+                    let base = self.get_chunk_mut_ptr(a_chunk);
+                    ptr::swap_nonoverlapping(base.add(a_off), base.add(b_off), run);
+                } else {
+                    let a_ptr = self.get_elem_mut_ptr(a_chunk, a_off);
+                    let b_ptr = self.get_elem_mut_ptr(b_chunk, b_off);
+                    ptr::swap_nonoverlapping(a_ptr, b_ptr, run);
+                }
+            }
+            a += run;
+            b += run;
+            count -= run;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ChunkedVec, ChunkedVecSized};
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    fn filled<const N: usize>(len: usize) -> ChunkedVec<String, N> {
+        let mut vec = ChunkedVecSized::new();
+        vec.extend((0..len).map(|i| i.to_string()));
+        vec
+    }
+
+    fn check_reorder<const N: usize>(len: usize) {
+        let expected: Vec<String> = (0..len).map(|i| i.to_string()).collect();
+
+        let mut vec = filled::<N>(len);
+        vec.reverse();
+        let mut reversed = expected.clone();
+        reversed.reverse();
+        assert_eq!(vec, reversed, "reverse, N = {N}, len = {len}");
+
+        for mid in 0..=len {
+            let mut vec = filled::<N>(len);
+            vec.rotate_left(mid);
+            let mut rotated = expected.clone();
+            rotated.rotate_left(mid);
+            assert_eq!(vec, rotated, "rotate_left({mid}), N = {N}, len = {len}");
+
+            let mut vec = filled::<N>(len);
+            vec.rotate_right(mid);
+            let mut rotated = expected.clone();
+            rotated.rotate_right(mid);
+            assert_eq!(vec, rotated, "rotate_right({mid}), N = {N}, len = {len}");
+        }
+    }
+
+    #[test]
+    fn test_reverse_and_rotate_match_slice() {
+        for len in 0..30 {
+            check_reorder::<1>(len);
+            check_reorder::<2>(len);
+            check_reorder::<3>(len);
+            check_reorder::<4>(len);
+            check_reorder::<7>(len);
+            check_reorder::<64>(len);
+        }
+    }
+
+    #[test]
+    fn test_reverse_range_with_unaligned_bounds() {
+        let mut vec: ChunkedVec<i32, 4> = ChunkedVecSized::new();
+        vec.extend(0..14);
+        vec.reverse_range(3, 13);
+        assert_eq!(vec, [0, 1, 2, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 13]);
+    }
+
+    #[test]
+    fn test_rotate_large() {
+        let mut vec: ChunkedVec<u32, 64> = ChunkedVecSized::new();
+        vec.extend(0..10_000);
+        let mut expected: Vec<u32> = (0..10_000).collect();
+
+        for mid in [1, 63, 64, 65, 4_999, 5_000, 9_999] {
+            vec.rotate_left(mid);
+            expected.rotate_left(mid);
+            assert_eq!(vec, expected);
+        }
+    }
+
+    #[test]
+    fn test_rotate_out_of_bounds() {
+        let mut vec: ChunkedVec<i32, 2> = ChunkedVecSized::new();
+        vec.extend([1, 2, 3]);
+
+        let left = catch_unwind(AssertUnwindSafe(|| vec.rotate_left(4)));
+        assert!(left.is_err());
+        let right = catch_unwind(AssertUnwindSafe(|| vec.rotate_right(4)));
+        assert!(right.is_err());
+        assert_eq!(vec, [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_swap() {
+        let mut vec: ChunkedVec<i32, 3> = ChunkedVecSized::new();
+        vec.extend(0..5);
+
+        vec.swap(0, 1);
+        assert_eq!(vec, [1, 0, 2, 3, 4]);
+        vec.swap(4, 0);
+        assert_eq!(vec, [4, 0, 2, 3, 1]);
+        vec.swap(2, 2);
+        assert_eq!(vec, [4, 0, 2, 3, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index out of bounds: index 5 >= length 5")]
+    fn test_swap_out_of_bounds() {
+        let mut vec: ChunkedVec<i32, 3> = ChunkedVecSized::new();
+        vec.extend(0..5);
+        vec.swap(1, 5);
+    }
+
+    #[test]
+    fn test_is_sorted() {
+        let mut vec: ChunkedVec<i32, 3> = ChunkedVecSized::new();
+        assert!(vec.is_sorted());
+        vec.push(7);
+        assert!(vec.is_sorted());
+
+        vec.clear();
+        vec.extend([1, 2, 2, 4, 5, 6, 9]);
+        assert!(vec.is_sorted());
+        assert!(!vec.is_sorted_by(|a, b| a < b));
+        assert!(vec.is_sorted_by_key(|&x| x / 2));
+
+        // Out of order only across the boundary between chunks 0 and 1
+        vec[3] = 1;
+        assert!(!vec.is_sorted());
+        assert!(vec.chunks().all(|c| c.windows(2).all(|w| w[0] <= w[1])));
+    }
+
+    #[test]
+    fn test_is_sorted_ignores_retained_chunks() {
+        let mut vec: ChunkedVec<i32, 2> = ChunkedVecSized::new();
+        vec.extend([1, 2, 3, 0, 0, 0]);
+        vec.truncate(3);
+        assert!(vec.is_sorted());
+    }
+}
@@ -0,0 +1,396 @@
+use crate::ChunkedVec;
+use std::ptr;
+
+/// Implementation of in-place filtering for ChunkedVec.
+///
+/// Every method here is a single forward compaction pass: kept elements are
+/// moved down into the holes left by removed ones, crossing chunk boundaries
+/// as needed, and allocated chunks are retained.
+///
+/// If a caller-supplied closure (or an element's `Drop`) panics, the pass is
+/// abandoned: the unprocessed tail is shifted down behind the kept prefix, so
+/// every element is dropped at most once and no initialized slot is left past
+/// `len`.
+impl<T, const N: usize> ChunkedVec<T, N> {
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// In other words, remove all elements `e` for which `f(&e)` returns
+    /// `false`. This method operates in place, visiting each element exactly
+    /// once in the original order, and preserves the order of the retained
+    /// elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let mut vec = chunked_vec![1, 2, 3, 4, 5, 6];
+    /// vec.retain(|&x| x % 2 == 0);
+    /// assert_eq!(vec, [2, 4, 6]);
+    /// ```
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.retain_mut(|elem| f(elem));
+    }
+
+    /// Retains only the elements specified by the predicate, passing a
+    /// mutable reference to it.
+    ///
+    /// In other words, remove all elements `e` such that `f(&mut e)` returns
+    /// `false`. This method operates in place, visiting each element exactly
+    /// once in the original order, and preserves the order of the retained
+    /// elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let mut vec = chunked_vec![1, 2, 3, 4];
+    /// vec.retain_mut(|x| {
+    ///     *x *= 10;
+    ///     *x <= 30
+    /// });
+    /// assert_eq!(vec, [10, 20, 30]);
+    /// ```
+    pub fn retain_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        /// Closes the gap and restores the length when the pass ends, even
+        /// by unwinding.
+        struct BackshiftOnDrop<'a, T, const N: usize> {
+            vec: &'a mut ChunkedVec<T, N>,
+            processed: usize,
+            deleted: usize,
+            original_len: usize,
+        }
+
+        impl<T, const N: usize> Drop for BackshiftOnDrop<'_, T, N> {
+            fn drop(&mut self) {
+                if self.deleted > 0 {
+                    // Safety: `[processed, original_len)` is the initialized,
+                    // unvisited tail; it moves down to the end of the kept
+                    // prefix.
+                    unsafe {
+                        self.vec.move_range_down(
+                            self.processed,
+                            self.processed - self.deleted,
+                            self.original_len - self.processed,
+                        );
+                    }
+                }
+                self.vec.len = self.original_len - self.deleted;
+            }
+        }
+
+        let original_len = self.len;
+        // Nothing is counted as live while the pass runs, so a leaked guard
+        // can only leak elements, never expose holes.
+        self.len = 0;
+        let mut g = BackshiftOnDrop {
+            vec: self,
+            processed: 0,
+            deleted: 0,
+            original_len,
+        };
+
+        while g.processed != original_len {
+            let (chunk_idx, offset) = g.vec.chunk_and_offset(g.processed);
+            // Safety: `processed < original_len`, so the slot is initialized.
+            let cur = unsafe { g.vec.get_elem_mut_ptr(chunk_idx, offset) };
+            if !f(unsafe { &mut *cur }) {
+                // Advance first so a panicking drop is not repeated by the
+                // guard.
+                g.processed += 1;
+                g.deleted += 1;
+                unsafe { ptr::drop_in_place(cur) };
+                continue;
+            }
+            if g.deleted > 0 {
+                // Safety: the hole is a slot whose element was dropped or
+                // already moved out, and lies before `cur`.
+                unsafe {
+                    let (cur, hole) = g
+                        .vec
+                        .get_elem_mut_ptr_pair(g.processed, g.processed - g.deleted);
+                    ptr::copy_nonoverlapping(cur, hole, 1);
+                }
+            }
+            g.processed += 1;
+        }
+    }
+
+    /// Removes all but the first of consecutive elements in the vector that
+    /// resolve to the same key.
+    ///
+    /// If the vector is sorted, this removes all duplicates.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let mut vec = chunked_vec![10, 20, 21, 30, 20];
+    /// vec.dedup_by_key(|i| *i / 10);
+    /// assert_eq!(vec, [10, 20, 30, 20]);
+    /// ```
+    pub fn dedup_by_key<F, K>(&mut self, mut key: F)
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
+
+    /// Removes all but the first of consecutive elements in the vector
+    /// satisfying a given equality relation.
+    ///
+    /// The `same_bucket` function is passed references to two elements from
+    /// the vector: the candidate first, then the last element kept before
+    /// it. If it returns `true`, the candidate is removed.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let mut vec = chunked_vec!["foo", "bar", "Bar", "baz", "bar"];
+    /// vec.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+    /// assert_eq!(vec, ["foo", "bar", "baz", "bar"]);
+    /// ```
+    pub fn dedup_by<F>(&mut self, mut same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        /// Closes the gap and restores the length when the pass ends, even
+        /// by unwinding.
+        struct FillGapOnDrop<'a, T, const N: usize> {
+            vec: &'a mut ChunkedVec<T, N>,
+            read: usize,
+            write: usize,
+            original_len: usize,
+        }
+
+        impl<T, const N: usize> Drop for FillGapOnDrop<'_, T, N> {
+            fn drop(&mut self) {
+                // Safety: `[read, original_len)` is the initialized,
+                // unvisited tail and `write <= read`.
+                unsafe {
+                    self.vec
+                        .move_range_down(self.read, self.write, self.original_len - self.read);
+                }
+                self.vec.len = self.write + (self.original_len - self.read);
+            }
+        }
+
+        let original_len = self.len;
+        if original_len <= 1 {
+            return;
+        }
+
+        // As in `retain_mut`: the guard restores the length.
+        self.len = 0;
+        let mut g = FillGapOnDrop {
+            vec: self,
+            read: 1,
+            write: 1,
+            original_len,
+        };
+
+        while g.read < original_len {
+            // Safety: `write - 1 < read < original_len`; both slots are
+            // initialized and distinct.
+            let (cur, prev) = unsafe { g.vec.get_elem_mut_ptr_pair(g.read, g.write - 1) };
+            if same_bucket(unsafe { &mut *cur }, unsafe { &mut *prev }) {
+                // Advance first so a panicking drop is not repeated by the
+                // guard.
+                g.read += 1;
+                unsafe { ptr::drop_in_place(cur) };
+            } else {
+                if g.read != g.write {
+                    // Safety: the slot at `write` was dropped or moved out.
+                    unsafe {
+                        let (cur, hole) = g.vec.get_elem_mut_ptr_pair(g.read, g.write);
+                        ptr::copy_nonoverlapping(cur, hole, 1);
+                    }
+                }
+                g.write += 1;
+                g.read += 1;
+            }
+        }
+    }
+
+    /// Removes consecutive repeated elements in the vector according to the
+    /// [`PartialEq`] trait implementation.
+    ///
+    /// If the vector is sorted, this removes all duplicates.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let mut vec = chunked_vec![1, 2, 2, 3, 2];
+    /// vec.dedup();
+    /// assert_eq!(vec, [1, 2, 3, 2]);
+    /// ```
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ChunkedVec, ChunkedVecSized};
+    use std::cell::Cell;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    struct Counted<'a> {
+        value: i32,
+        drops: &'a Cell<usize>,
+    }
+
+    impl Drop for Counted<'_> {
+        fn drop(&mut self) {
+            self.drops.set(self.drops.get() + 1);
+        }
+    }
+
+    fn counted<'a, const N: usize>(
+        values: &[i32],
+        drops: &'a Cell<usize>,
+    ) -> ChunkedVec<Counted<'a>, N> {
+        let mut vec = ChunkedVecSized::new();
+        for &value in values {
+            vec.push(Counted { value, drops });
+        }
+        vec
+    }
+
+    fn values<const N: usize>(vec: &ChunkedVec<Counted<'_>, N>) -> Vec<i32> {
+        vec.iter().map(|c| c.value).collect()
+    }
+
+    #[test]
+    fn retain_matches_vec() {
+        let data: Vec<i32> = (0..40).map(|i| (i * 7) % 11).collect();
+        for modulus in 1..5 {
+            let mut vec: ChunkedVec<i32, 3> = data.iter().copied().collect();
+            let mut expected = data.clone();
+
+            vec.retain(|x| x % modulus == 0);
+            expected.retain(|x| x % modulus == 0);
+
+            assert!(vec.iter().eq(expected.iter()));
+        }
+    }
+
+    #[test]
+    fn retain_keeps_chunks_and_drops_removed() {
+        let drops = Cell::new(0);
+        let mut vec = counted::<4>(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9], &drops);
+
+        vec.retain(|c| c.value % 3 == 0);
+        assert_eq!(values(&vec), [0, 3, 6, 9]);
+        assert_eq!(drops.get(), 6);
+        assert_eq!(vec.allocated_capacity(), 12);
+
+        drop(vec);
+        assert_eq!(drops.get(), 10);
+    }
+
+    #[test]
+    fn retain_mut_modifies() {
+        let mut vec: ChunkedVec<i32, 2> = (1..=7).collect();
+        vec.retain_mut(|x| {
+            *x *= 2;
+            *x % 3 != 0
+        });
+        assert_eq!(vec, [2, 4, 8, 10, 14]);
+    }
+
+    #[test]
+    fn retain_panicking_predicate() {
+        let drops = Cell::new(0);
+        let mut vec = counted::<3>(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9], &drops);
+
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            vec.retain(|c| {
+                if c.value == 6 {
+                    panic!("predicate panic");
+                }
+                c.value % 2 == 0
+            })
+        }));
+        assert!(result.is_err());
+
+        // Visited odd elements are dropped once; the tail from the
+        // panicking element onwards is kept.
+        assert_eq!(drops.get(), 3);
+        assert_eq!(values(&vec), [0, 2, 4, 6, 7, 8, 9]);
+
+        drop(vec);
+        assert_eq!(drops.get(), 10);
+    }
+
+    #[test]
+    fn dedup_matches_vec() {
+        fn check<const N: usize>(data: &[i32]) {
+            let mut vec: ChunkedVec<i32, N> = data.iter().copied().collect();
+            let mut expected = data.to_vec();
+            vec.dedup();
+            expected.dedup();
+            assert!(vec.iter().eq(expected.iter()));
+        }
+
+        let data: Vec<i32> = (0..60).map(|i| (i / 3 + i % 2) % 5).collect();
+        check::<1>(&data);
+        check::<3>(&data);
+        check::<8>(&data);
+    }
+
+    #[test]
+    fn dedup_empty_and_single() {
+        let mut vec: ChunkedVec<i32, 2> = ChunkedVecSized::new();
+        vec.dedup();
+        assert!(vec.is_empty());
+
+        vec.push(1);
+        vec.dedup();
+        assert_eq!(vec, [1]);
+    }
+
+    #[test]
+    fn dedup_by_key_and_drops() {
+        let drops = Cell::new(0);
+        let mut vec = counted::<2>(&[1, 1, 2, 2, 2, 3, 1, 1], &drops);
+
+        vec.dedup_by_key(|c| c.value);
+        assert_eq!(values(&vec), [1, 2, 3, 1]);
+        assert_eq!(drops.get(), 4);
+
+        drop(vec);
+        assert_eq!(drops.get(), 8);
+    }
+
+    #[test]
+    fn dedup_by_panicking_comparator() {
+        let drops = Cell::new(0);
+        let mut vec = counted::<3>(&[1, 1, 2, 2, 3, 3, 4, 4], &drops);
+
+        let mut calls = 0;
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            vec.dedup_by(|a, b| {
+                calls += 1;
+                if calls == 5 {
+                    panic!("comparator panic");
+                }
+                a.value == b.value
+            })
+        }));
+        assert!(result.is_err());
+
+        // Four comparisons removed the second 1 and 2; the rest is kept.
+        assert_eq!(drops.get(), 2);
+        assert_eq!(values(&vec), [1, 2, 3, 3, 4, 4]);
+
+        drop(vec);
+        assert_eq!(drops.get(), 8);
+    }
+}
@@ -0,0 +1,237 @@
+use crate::ChunkedVec;
+use std::cmp::Ordering;
+
+/// Implementation of binary searching for ChunkedVec.
+///
+/// Every search runs in two steps: a binary search over the first element of
+/// each chunk selects the only chunk that can hold the answer, then the
+/// standard library's slice search runs on that chunk's contiguous slice.
+/// Both steps together take *O*(log *n*) comparisons.
+impl<T, const N: usize> ChunkedVec<T, N> {
+    /// Binary searches this vector for a given element.
+    ///
+    /// If the vector is not sorted, the returned result is unspecified and
+    /// meaningless.
+    ///
+    /// If the value is found then [`Result::Ok`] is returned, containing the
+    /// index of the matching element. If there are multiple matches, then any
+    /// one of the matches could be returned. If the value is not found then
+    /// [`Result::Err`] is returned, containing the index where a matching
+    /// element could be inserted while maintaining sorted order.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkedVec, ChunkedVecSized};
+    /// let mut vec: ChunkedVec<i32, 4> = ChunkedVecSized::new();
+    /// vec.extend([0, 1, 1, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55]);
+    ///
+    /// assert_eq!(vec.binary_search(&13), Ok(9));
+    /// assert_eq!(vec.binary_search(&4), Err(7));
+    /// assert_eq!(vec.binary_search(&100), Err(13));
+    /// let r = vec.binary_search(&1);
+    /// assert!(matches!(r, Ok(1..=4)));
+    /// ```
+    pub fn binary_search(&self, x: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        self.binary_search_by(|p| p.cmp(x))
+    }
+
+    /// Binary searches this vector with a comparator function.
+    ///
+    /// The comparator function should return an order code that indicates
+    /// whether its argument is `Less`, `Equal` or `Greater` the desired
+    /// target. If the vector is not sorted or if the comparator function does
+    /// not implement an order consistent with the sort order of the
+    /// underlying vector, the returned result is unspecified and meaningless.
+    ///
+    /// The return value follows the same convention as
+    /// [`binary_search`](ChunkedVec::binary_search).
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkedVec, ChunkedVecSized};
+    /// let mut vec: ChunkedVec<i32, 4> = ChunkedVecSized::new();
+    /// vec.extend([0, 1, 1, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55]);
+    ///
+    /// assert_eq!(vec.binary_search_by(|probe| probe.cmp(&13)), Ok(9));
+    /// assert_eq!(vec.binary_search_by(|probe| probe.cmp(&4)), Err(7));
+    /// ```
+    pub fn binary_search_by<F>(&self, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        // The answer lies in the last chunk that starts at or below the target.
+        let chunks = self.partition_chunks(|first| f(first) != Ordering::Greater);
+        if chunks == 0 {
+            return Err(0);
+        }
+        let chunk_idx = chunks - 1;
+        let base = chunk_idx * N;
+        // `chunk_idx` is below the number of initialized chunks, so it exists.
+        match self.chunk(chunk_idx).unwrap().binary_search_by(f) {
+            Ok(i) => Ok(base + i),
+            Err(i) => Err(base + i),
+        }
+    }
+
+    /// Binary searches this vector with a key extraction function.
+    ///
+    /// Assumes that the vector is sorted by the key, for instance with
+    /// [`sort_by_key`](ChunkedVec::sort_by_key) using the same key extraction
+    /// function. If it is not, the returned result is unspecified and
+    /// meaningless.
+    ///
+    /// The return value follows the same convention as
+    /// [`binary_search`](ChunkedVec::binary_search).
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkedVec, ChunkedVecSized};
+    /// let mut vec: ChunkedVec<(i32, i32), 2> = ChunkedVecSized::new();
+    /// vec.extend([(0, 0), (2, 1), (4, 1), (5, 1), (3, 1), (1, 2), (2, 3)]);
+    ///
+    /// assert_eq!(vec.binary_search_by_key(&2, |&(_, b)| b), Ok(5));
+    /// assert_eq!(vec.binary_search_by_key(&4, |&(_, b)| b), Err(7));
+    /// ```
+    pub fn binary_search_by_key<B, F>(&self, b: &B, mut f: F) -> Result<usize, usize>
+    where
+        B: Ord,
+        F: FnMut(&T) -> B,
+    {
+        self.binary_search_by(|k| f(k).cmp(b))
+    }
+
+    /// Returns the index of the partition point according to the given
+    /// predicate (the index of the first element of the second partition).
+    ///
+    /// The vector is assumed to be partitioned according to the given
+    /// predicate: all elements for which it returns `true` are at the start
+    /// and all elements for which it returns `false` are at the end. If this
+    /// is not the case, the returned result is unspecified and meaningless.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkedVec, ChunkedVecSized};
+    /// let mut vec: ChunkedVec<i32, 3> = ChunkedVecSized::new();
+    /// vec.extend([1, 2, 3, 3, 5, 6, 7]);
+    ///
+    /// let i = vec.partition_point(|&x| x < 5);
+    /// assert_eq!(i, 4);
+    /// assert!(vec.iter().take(i).all(|&x| x < 5));
+    /// assert!(vec.iter().skip(i).all(|&x| !(x < 5)));
+    /// ```
+    pub fn partition_point<P>(&self, mut pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let chunks = self.partition_chunks(&mut pred);
+        if chunks == 0 {
+            return 0;
+        }
+        let chunk_idx = chunks - 1;
+        // `chunk_idx` is below the number of initialized chunks, so it exists.
+        chunk_idx * N + self.chunk(chunk_idx).unwrap().partition_point(pred)
+    }
+
+    /// Returns the number of initialized chunks whose first element
+    /// satisfies `pred`, assuming those chunks come first.
+    fn partition_chunks<P>(&self, mut pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let (mut lo, mut hi) = (0, self.len.div_ceil(N));
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            // Safety: `mid` is an initialized chunk, so its first slot is
+            // initialized.
+            if pred(unsafe { &*self.get_chunk_ptr(mid) }) {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        lo
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ChunkedVec, ChunkedVecSized};
+
+    fn check_search<const N: usize>(data: &[i32]) {
+        let mut vec: ChunkedVec<i32, N> = ChunkedVecSized::new();
+        vec.extend(data.iter().copied());
+
+        for x in -1..=data.last().map_or(0, |&last| last + 1) {
+            match (vec.binary_search(&x), data.binary_search(&x)) {
+                (Ok(i), Ok(_)) => assert_eq!(vec[i], x),
+                (Err(i), Err(j)) => assert_eq!(i, j),
+                (actual, expected) => panic!("{x}: {actual:?} vs {expected:?}"),
+            }
+            assert_eq!(
+                vec.partition_point(|&y| y < x),
+                data.partition_point(|&y| y < x)
+            );
+            assert_eq!(
+                vec.partition_point(|&y| y <= x),
+                data.partition_point(|&y| y <= x)
+            );
+        }
+    }
+
+    #[test]
+    fn test_binary_search_matches_slice() {
+        for len in 0..40 {
+            // Distinct values with gaps, and runs of duplicates
+            let sparse: Vec<i32> = (0..len).map(|i| i * 2).collect();
+            let dups: Vec<i32> = (0..len).map(|i| i / 3).collect();
+            for data in [&sparse, &dups] {
+                check_search::<1>(data);
+                check_search::<3>(data);
+                check_search::<4>(data);
+                check_search::<64>(data);
+            }
+        }
+    }
+
+    #[test]
+    fn test_binary_search_across_chunk_boundary() {
+        // The run of 5s straddles the boundary between chunks 0 and 1
+        let mut vec: ChunkedVec<i32, 4> = ChunkedVecSized::new();
+        vec.extend([1, 2, 5, 5, 5, 5, 7, 9]);
+
+        let i = vec.binary_search(&5).unwrap();
+        assert!((2..=5).contains(&i));
+        assert_eq!(vec.partition_point(|&x| x < 5), 2);
+        assert_eq!(vec.partition_point(|&x| x <= 5), 6);
+        assert_eq!(vec.binary_search(&4), Err(2));
+        assert_eq!(vec.binary_search(&6), Err(6));
+    }
+
+    #[test]
+    fn test_binary_search_by_key() {
+        let mut vec: ChunkedVec<(u32, &str), 2> = ChunkedVecSized::new();
+        vec.extend([(1, "a"), (3, "b"), (5, "c"), (7, "d"), (9, "e")]);
+
+        assert_eq!(vec.binary_search_by_key(&7, |&(k, _)| k), Ok(3));
+        assert_eq!(vec.binary_search_by_key(&0, |&(k, _)| k), Err(0));
+        assert_eq!(vec.binary_search_by_key(&8, |&(k, _)| k), Err(4));
+        assert_eq!(vec.binary_search_by_key(&10, |&(k, _)| k), Err(5));
+    }
+
+    #[test]
+    fn test_search_ignores_retained_chunks() {
+        let mut vec: ChunkedVec<i32, 2> = ChunkedVecSized::new();
+        vec.extend(0..10);
+        vec.truncate(3);
+
+        assert_eq!(vec.binary_search(&8), Err(3));
+        assert_eq!(vec.partition_point(|_| true), 3);
+
+        vec.clear();
+        assert_eq!(vec.binary_search(&0), Err(0));
+        assert_eq!(vec.partition_point(|_| true), 0);
+    }
+}
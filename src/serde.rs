@@ -1,7 +1,10 @@
-//! [`Serialize`] and [`Deserialize`] implementations for [`ChunkedVec`].
+//! [`Serialize`] and [`Deserialize`] implementations for [`ChunkedVec`] and
+//! [`PersistentChunkedVec`].
 //!
 //! `ChunkedVec<T, N>` is serialized as a plain sequence of its elements,
 //! exactly like `Vec<T>`, so the two are interchangeable on the wire. The
+//! same goes for `PersistentChunkedVec<T, N>`; which chunks are shared is not
+//! part of the format either. The
 //! chunk size `N` is a memory-layout detail and is not part of the format.
 
 use core::fmt;
@@ -10,7 +13,7 @@ use core::marker::PhantomData;
 use serde::de::{Deserialize, Deserializer, SeqAccess, Visitor};
 use serde::ser::{Serialize, SerializeSeq, Serializer};
 
-use crate::{ChunkedVec, ChunkedVecSized};
+use crate::{ChunkedVec, ChunkedVecSized, PersistentChunkedVec};
 
 /// Serializes the `ChunkedVec` as a sequence of its elements.
 ///
@@ -78,3 +81,46 @@ where
         deserializer.deserialize_seq(ChunkedVecVisitor(PhantomData))
     }
 }
+
+/// Serializes the `PersistentChunkedVec` as a sequence of its elements.
+///
+/// # Examples
+/// ```
+/// use chunked_vec::PersistentChunkedVec;
+///
+/// let vec: PersistentChunkedVec<i32> = (1..=3).collect();
+/// assert_eq!(serde_json::to_string(&vec).unwrap(), "[1,2,3]");
+/// ```
+impl<T, const N: usize> Serialize for PersistentChunkedVec<T, N>
+where
+    T: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for elem in self.iter() {
+            seq.serialize_element(elem)?;
+        }
+        seq.end()
+    }
+}
+
+/// Deserializes a sequence of elements into a `PersistentChunkedVec`.
+///
+/// The elements are read into a `ChunkedVec` first, and its chunks are then
+/// moved in without cloning.
+///
+/// # Examples
+/// ```
+/// use chunked_vec::PersistentChunkedVec;
+///
+/// let vec: PersistentChunkedVec<i32> = serde_json::from_str("[1,2,3]").unwrap();
+/// assert_eq!(vec[2], 3);
+/// ```
+impl<'de, T, const N: usize> Deserialize<'de> for PersistentChunkedVec<T, N>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        ChunkedVec::<T, N>::deserialize(deserializer).map(PersistentChunkedVec::from)
+    }
+}
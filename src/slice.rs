@@ -0,0 +1,664 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Index, IndexMut, RangeBounds};
+use std::ptr::NonNull;
+
+use crate::internal::resolve_range;
+use crate::iterators::{Iter, IterMut};
+use crate::ChunkedVec;
+
+/// A borrowed view into a contiguous range of a [`ChunkedVec`].
+///
+/// A `ChunkedSlice` is to `ChunkedVec` what `&[T]` is to `Vec<T>`: it is a
+/// start offset and a length over the vector's chunk table, so creating one,
+/// sub-slicing it or splitting it never copies elements. It is created by
+/// [`ChunkedVec::slice`].
+///
+/// # Examples
+/// ```
+/// use chunked_vec::ChunkedVec;
+///
+/// let vec: ChunkedVec<i32, 4> = (0..10).collect();
+/// let slice = vec.slice(2..8);
+/// assert_eq!(slice.len(), 6);
+/// assert_eq!(slice[0], 2);
+///
+/// let (left, right) = slice.split_at(2);
+/// assert!(left.iter().eq([2, 3].iter()));
+/// assert!(right.iter().eq([4, 5, 6, 7].iter()));
+/// ```
+pub struct ChunkedSlice<'a, T, const N: usize> {
+    pub(crate) vec: &'a ChunkedVec<T, N>,
+    pub(crate) start: usize,
+    pub(crate) len: usize,
+}
+
+// Safety: the slice only gives shared access to the elements, like `&[T]`.
+unsafe impl<T: Sync, const N: usize> Send for ChunkedSlice<'_, T, N> {}
+
+// Safety: see above.
+unsafe impl<T: Sync, const N: usize> Sync for ChunkedSlice<'_, T, N> {}
+
+/// A mutable borrowed view into a contiguous range of a [`ChunkedVec`].
+///
+/// This is the mutable counterpart of [`ChunkedSlice`], created by
+/// [`ChunkedVec::slice_mut`]. Besides element access it can sort just the
+/// range it covers, and [`split_at_mut`](ChunkedSliceMut::split_at_mut)
+/// divides it into two mutable views of disjoint ranges.
+///
+/// # Examples
+/// ```
+/// use chunked_vec::chunked_vec;
+///
+/// let mut vec = chunked_vec![9, 8, 7, 6, 5, 4, 3];
+/// vec.slice_mut(1..5).sort_unstable();
+/// assert_eq!(vec, [9, 5, 6, 7, 8, 4, 3]);
+/// ```
+pub struct ChunkedSliceMut<'a, T, const N: usize> {
+    /// The borrowed vector. It is held as a pointer so that two views of
+    /// disjoint ranges can exist at once; a view only ever touches the
+    /// elements in its own range, and never the chunk table or the length.
+    pub(crate) vec: NonNull<ChunkedVec<T, N>>,
+    pub(crate) start: usize,
+    pub(crate) len: usize,
+    pub(crate) _marker: PhantomData<&'a mut ChunkedVec<T, N>>,
+}
+
+// Safety: the slice gives mutable access to the elements, like `&mut [T]`.
+unsafe impl<T: Send, const N: usize> Send for ChunkedSliceMut<'_, T, N> {}
+
+// Safety: a shared reference to it only gives shared access to the
+// elements.
+unsafe impl<T: Sync, const N: usize> Sync for ChunkedSliceMut<'_, T, N> {}
+
+impl<T, const N: usize> ChunkedVec<T, N> {
+    /// Returns a borrowed view of the elements in `range`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is greater than its end, or if the
+    /// end is greater than the length of the vector.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let vec = chunked_vec![1, 2, 3, 4, 5];
+    /// assert!(vec.slice(1..3).iter().eq([2, 3].iter()));
+    /// assert_eq!(vec.slice(..).len(), 5);
+    /// ```
+    pub fn slice<R>(&self, range: R) -> ChunkedSlice<'_, T, N>
+    where
+        R: RangeBounds<usize>,
+    {
+        let range = resolve_range(range, self.len);
+        ChunkedSlice {
+            vec: self,
+            start: range.start,
+            len: range.len(),
+        }
+    }
+
+    /// Returns a mutable borrowed view of the elements in `range`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is greater than its end, or if the
+    /// end is greater than the length of the vector.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let mut vec = chunked_vec![1, 2, 3, 4, 5];
+    /// for x in vec.slice_mut(3..).iter_mut() {
+    ///     *x = 0;
+    /// }
+    /// assert_eq!(vec, [1, 2, 3, 0, 0]);
+    /// ```
+    pub fn slice_mut<R>(&mut self, range: R) -> ChunkedSliceMut<'_, T, N>
+    where
+        R: RangeBounds<usize>,
+    {
+        let range = resolve_range(range, self.len);
+        ChunkedSliceMut {
+            vec: NonNull::from(self),
+            start: range.start,
+            len: range.len(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T, const N: usize> ChunkedSlice<'a, T, N> {
+    /// Returns the number of elements in the slice.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the slice contains no elements.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a reference to the element at `index` within the slice, or
+    /// `None` if it is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let vec = chunked_vec![1, 2, 3, 4];
+    /// let slice = vec.slice(2..);
+    /// assert_eq!(slice.get(0), Some(&3));
+    /// assert_eq!(slice.get(2), None);
+    /// ```
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&'a T> {
+        if index >= self.len {
+            None
+        } else {
+            // Safety: `start + index < start + len <= vec.len`.
+            Some(unsafe { self.vec.get_unchecked(self.start + index) })
+        }
+    }
+
+    /// Returns an iterator over the elements of the slice.
+    pub fn iter(&self) -> Iter<'a, T, N> {
+        Iter::new(self.vec, self.start, self.start + self.len)
+    }
+
+    /// Returns a view of the elements in `range`, relative to this slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is greater than its end, or if the
+    /// end is greater than the length of the slice.
+    pub fn slice<R>(&self, range: R) -> ChunkedSlice<'a, T, N>
+    where
+        R: RangeBounds<usize>,
+    {
+        let range = resolve_range(range, self.len);
+        ChunkedSlice {
+            vec: self.vec,
+            start: self.start + range.start,
+            len: range.len(),
+        }
+    }
+
+    /// Divides the slice into two at an index.
+    ///
+    /// The first will contain all indices from `[0, mid)` and the second
+    /// will contain all indices from `[mid, len)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    pub fn split_at(&self, mid: usize) -> (ChunkedSlice<'a, T, N>, ChunkedSlice<'a, T, N>) {
+        assert!(mid <= self.len, "mid > len");
+        (self.slice(..mid), self.slice(mid..))
+    }
+}
+
+impl<'a, T, const N: usize> ChunkedSliceMut<'a, T, N> {
+    /// Returns the borrowed vector.
+    #[inline]
+    fn vec(&self) -> &ChunkedVec<T, N> {
+        // Safety: the vector is borrowed for `'a`, and the view only reads
+        // elements in its own range through this reference.
+        unsafe { self.vec.as_ref() }
+    }
+
+    /// Returns the borrowed vector mutably.
+    #[inline]
+    fn vec_mut(&mut self) -> &mut ChunkedVec<T, N> {
+        // Safety: the vector is borrowed mutably for `'a`, and the view only
+        // modifies elements in its own range through this reference.
+        unsafe { self.vec.as_mut() }
+    }
+
+    /// Returns the number of elements in the slice.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the slice contains no elements.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a reference to the element at `index` within the slice, or
+    /// `None` if it is out of bounds.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.as_slice().get(index)
+    }
+
+    /// Returns a mutable reference to the element at `index` within the
+    /// slice, or `None` if it is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let mut vec = chunked_vec![1, 2, 3, 4];
+    /// let mut slice = vec.slice_mut(2..);
+    /// *slice.get_mut(1).unwrap() = 40;
+    /// assert_eq!(vec, [1, 2, 3, 40]);
+    /// ```
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len {
+            None
+        } else {
+            let index = self.start + index;
+            // Safety: `start + index < start + len <= vec.len`.
+            Some(unsafe { self.vec_mut().get_unchecked_mut(index) })
+        }
+    }
+
+    /// Returns a shared view of the same range.
+    pub fn as_slice(&self) -> ChunkedSlice<'_, T, N> {
+        ChunkedSlice {
+            vec: self.vec(),
+            start: self.start,
+            len: self.len,
+        }
+    }
+
+    /// Returns an iterator over the elements of the slice.
+    pub fn iter(&self) -> Iter<'_, T, N> {
+        Iter::new(self.vec(), self.start, self.start + self.len)
+    }
+
+    /// Returns an iterator that allows modifying each element of the slice.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, N> {
+        let (start, end) = (self.start, self.start + self.len);
+        IterMut::new(self.vec_mut(), start, end)
+    }
+
+    /// Returns a view of the elements in `range`, relative to this slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is greater than its end, or if the
+    /// end is greater than the length of the slice.
+    pub fn slice<R>(&self, range: R) -> ChunkedSlice<'_, T, N>
+    where
+        R: RangeBounds<usize>,
+    {
+        let range = resolve_range(range, self.len);
+        ChunkedSlice {
+            vec: self.vec(),
+            start: self.start + range.start,
+            len: range.len(),
+        }
+    }
+
+    /// Returns a mutable view of the elements in `range`, relative to this
+    /// slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is greater than its end, or if the
+    /// end is greater than the length of the slice.
+    pub fn slice_mut<R>(&mut self, range: R) -> ChunkedSliceMut<'_, T, N>
+    where
+        R: RangeBounds<usize>,
+    {
+        let range = resolve_range(range, self.len);
+        ChunkedSliceMut {
+            vec: self.vec,
+            start: self.start + range.start,
+            len: range.len(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Divides the slice into two shared views at an index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    pub fn split_at(&self, mid: usize) -> (ChunkedSlice<'_, T, N>, ChunkedSlice<'_, T, N>) {
+        assert!(mid <= self.len, "mid > len");
+        (self.slice(..mid), self.slice(mid..))
+    }
+
+    /// Divides the slice into two mutable views at an index.
+    ///
+    /// The first will contain all indices from `[0, mid)` and the second
+    /// will contain all indices from `[mid, len)`. Both can be used at the
+    /// same time, including from different threads.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let mut vec = chunked_vec![5, 4, 3, 2, 1, 0];
+    /// let mut slice = vec.slice_mut(..);
+    /// let (mut left, mut right) = slice.split_at_mut(3);
+    /// left.sort_unstable();
+    /// right[0] = 20;
+    /// assert_eq!(vec, [3, 4, 5, 20, 1, 0]);
+    /// ```
+    pub fn split_at_mut(
+        &mut self,
+        mid: usize,
+    ) -> (ChunkedSliceMut<'_, T, N>, ChunkedSliceMut<'_, T, N>) {
+        assert!(mid <= self.len, "mid > len");
+        // The two views cover the disjoint ranges `[start, start + mid)` and
+        // `[start + mid, start + len)` of the vector borrowed by `self`.
+        let left = ChunkedSliceMut {
+            vec: self.vec,
+            start: self.start,
+            len: mid,
+            _marker: PhantomData,
+        };
+        let right = ChunkedSliceMut {
+            vec: self.vec,
+            start: self.start + mid,
+            len: self.len - mid,
+            _marker: PhantomData,
+        };
+        (left, right)
+    }
+
+    /// Sorts the slice, but might not preserve the order of equal elements.
+    ///
+    /// Only the covered range is reordered; see
+    /// [`ChunkedVec::sort_unstable`].
+    pub fn sort_unstable(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_unstable_by(T::cmp);
+    }
+
+    /// Sorts the slice with a comparison function, but might not preserve
+    /// the order of equal elements.
+    ///
+    /// Only the covered range is reordered; see
+    /// [`ChunkedVec::sort_unstable_by`].
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let mut vec = chunked_vec![1, 2, 3, 4, 5, 6];
+    /// vec.slice_mut(..4).sort_unstable_by(|a, b| b.cmp(a));
+    /// assert_eq!(vec, [4, 3, 2, 1, 5, 6]);
+    /// ```
+    pub fn sort_unstable_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let (start, end) = (self.start, self.start + self.len);
+        self.vec_mut().sort_range_unstable_by(start, end, compare);
+    }
+
+    /// Sorts the slice with a key extraction function, but might not
+    /// preserve the order of equal elements.
+    ///
+    /// Only the covered range is reordered; see
+    /// [`ChunkedVec::sort_unstable_by_key`].
+    pub fn sort_unstable_by_key<K, F>(&mut self, mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.sort_unstable_by(|a, b| f(a).cmp(&f(b)));
+    }
+}
+
+impl<T, const N: usize> Clone for ChunkedSlice<'_, T, N> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, const N: usize> Copy for ChunkedSlice<'_, T, N> {}
+
+impl<'a, T, const N: usize> IntoIterator for ChunkedSlice<'a, T, N> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for ChunkedSliceMut<'a, T, N> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        // Safety: the vector is borrowed mutably for `'a`, and the iterator
+        // only visits the elements in this view's range.
+        let vec = unsafe { &mut *self.vec.as_ptr() };
+        IterMut::new(vec, self.start, self.start + self.len)
+    }
+}
+
+impl<T, const N: usize> Index<usize> for ChunkedSlice<'_, T, N> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        match self.get(index) {
+            Some(elem) => elem,
+            None => panic!(
+                "Index out of bounds: index {} >= length {}",
+                index, self.len
+            ),
+        }
+    }
+}
+
+impl<T, const N: usize> Index<usize> for ChunkedSliceMut<'_, T, N> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        if index >= self.len {
+            panic!(
+                "Index out of bounds: index {} >= length {}",
+                index, self.len
+            );
+        }
+        // Safety: We have already checked the index bounds
+        unsafe { self.vec().get_unchecked(self.start + index) }
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for ChunkedSliceMut<'_, T, N> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        if index >= self.len {
+            panic!(
+                "Index out of bounds: index {} >= length {}",
+                index, self.len
+            );
+        }
+        let index = self.start + index;
+        // Safety: We have already checked the index bounds
+        unsafe { self.vec_mut().get_unchecked_mut(index) }
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for ChunkedSlice<'_, T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for ChunkedSliceMut<'_, T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ChunkedVec, ChunkedVecSized};
+
+    fn sample<const N: usize>(len: i32) -> ChunkedVec<i32, N> {
+        let mut vec = ChunkedVecSized::new();
+        vec.extend(0..len);
+        vec
+    }
+
+    #[test]
+    fn slice_matches_std_ranges() {
+        let vec = sample::<3>(10);
+        let expected: Vec<i32> = (0..10).collect();
+
+        for start in 0..=10 {
+            for end in start..=10 {
+                let slice = vec.slice(start..end);
+                assert_eq!(slice.len(), end - start);
+                assert!(slice.iter().eq(expected[start..end].iter()));
+                assert!(slice.iter().rev().eq(expected[start..end].iter().rev()));
+                for i in 0..slice.len() {
+                    assert_eq!(slice[i], expected[start + i]);
+                }
+                assert_eq!(slice.get(slice.len()), None);
+            }
+        }
+
+        assert_eq!(vec.slice(..).len(), 10);
+        assert_eq!(vec.slice(..=3).len(), 4);
+        assert_eq!(vec.slice(7..).len(), 3);
+    }
+
+    #[test]
+    fn sub_slicing_and_split_at() {
+        let vec = sample::<4>(20);
+        let slice = vec.slice(3..17);
+        let sub = slice.slice(2..6);
+        assert!(sub.iter().eq([5, 6, 7, 8].iter()));
+        assert_eq!(sub.slice(1..).get(0), Some(&6));
+
+        let (left, right) = slice.split_at(5);
+        assert!(left.iter().eq((3..8).collect::<Vec<_>>().iter()));
+        assert!(right.iter().eq((8..17).collect::<Vec<_>>().iter()));
+
+        let (empty, all) = slice.split_at(0);
+        assert!(empty.is_empty());
+        assert_eq!(all.len(), 14);
+    }
+
+    #[test]
+    fn slice_mut_access() {
+        let mut vec = sample::<3>(10);
+        {
+            let mut slice = vec.slice_mut(4..9);
+            slice[0] = 40;
+            *slice.get_mut(4).unwrap() = 80;
+            assert_eq!(slice.get_mut(5), None);
+            for x in slice.slice_mut(1..4).iter_mut() {
+                *x *= -1;
+            }
+            assert_eq!(format!("{:?}", slice), "[40, -5, -6, -7, 80]");
+        }
+        assert_eq!(vec, [0, 1, 2, 3, 40, -5, -6, -7, 80, 9]);
+    }
+
+    #[test]
+    fn split_at_mut_halves_are_independent() {
+        let mut vec = sample::<4>(20);
+        {
+            let mut slice = vec.slice_mut(3..17);
+            let (mut left, mut right) = slice.split_at_mut(6);
+            assert_eq!((left.len(), right.len()), (6, 8));
+
+            // Both halves share chunk 2 and are mutated while both are alive
+            for x in left.iter_mut().zip(right.iter_mut()) {
+                *x.0 *= 10;
+                *x.1 = -*x.1;
+            }
+            left[5] = 0;
+            right.sort_unstable();
+            assert_eq!(format!("{:?}", left), "[30, 40, 50, 60, 70, 0]");
+            assert_eq!(
+                format!("{:?}", right),
+                "[-14, -13, -12, -11, -10, -9, 15, 16]"
+            );
+
+            let (empty, all) = right.split_at_mut(0);
+            assert!(empty.is_empty());
+            assert_eq!(all.len(), 8);
+        }
+        let expected = [
+            0, 1, 2, 30, 40, 50, 60, 70, 0, -14, -13, -12, -11, -10, -9, 15, 16, 17, 18, 19,
+        ];
+        assert_eq!(vec, expected);
+    }
+
+    #[test]
+    fn split_at_mut_halves_on_two_threads() {
+        let mut vec: ChunkedVec<i32, 4> = (0..30).rev().collect();
+        let mut slice = vec.slice_mut(..);
+        let (mut left, mut right) = slice.split_at_mut(15);
+        std::thread::scope(|s| {
+            s.spawn(|| left.sort_unstable());
+            s.spawn(|| right.sort_unstable());
+        });
+        let expected: Vec<i32> = (15..30).chain(0..15).collect();
+        assert_eq!(vec, expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "mid > len")]
+    fn split_at_mut_out_of_bounds() {
+        let mut vec = sample::<4>(10);
+        vec.slice_mut(2..5).split_at_mut(4);
+    }
+
+    #[test]
+    fn sort_only_the_range() {
+        for len in [0, 1, 5, 13, 40] {
+            for (start, end) in [(0, len), (len / 3, len - len / 4), (len / 2, len / 2)] {
+                let data: Vec<i32> = (0..len as i32).map(|i| (i * 37) % 11).collect();
+                let mut vec: ChunkedVec<i32, 4> = data.iter().copied().collect();
+                let mut expected = data.clone();
+
+                vec.slice_mut(start..end).sort_unstable();
+                expected[start..end].sort_unstable();
+
+                assert!(vec.iter().eq(expected.iter()));
+            }
+        }
+    }
+
+    #[test]
+    fn sort_by_key_on_slice() {
+        let mut vec: ChunkedVec<(i32, char), 2> = ChunkedVecSized::new();
+        vec.extend([(3, 'a'), (2, 'b'), (1, 'c'), (0, 'd')]);
+        vec.slice_mut(1..).sort_unstable_by_key(|&(k, _)| k);
+        let chars: String = vec.iter().map(|&(_, c)| c).collect();
+        assert_eq!(chars, "adcb");
+    }
+
+    #[test]
+    #[should_panic(expected = "Index out of bounds: index 3 >= length 3")]
+    fn slice_index_out_of_bounds() {
+        let vec = sample::<4>(10);
+        let slice = vec.slice(2..5);
+        let _ = slice[3];
+    }
+
+    #[test]
+    #[should_panic(expected = "range end index 11 out of range for slice of length 10")]
+    fn slice_range_out_of_bounds() {
+        let vec = sample::<4>(10);
+        vec.slice(2..11);
+    }
+}
@@ -1,13 +1,92 @@
-use crate::ChunkedVec;
+use crate::{ChunkedSlice, ChunkedVec};
 use std::cmp::Ordering;
+use std::ptr;
+
+/// Ranges spanning chunks up to this length are insertion sorted.
+const MAX_INSERTION: usize = 20;
+
+/// A logical position tracked as a chunk index and offset, so that stepping
+/// to a neighbouring element needs no division.
+#[derive(Clone, Copy)]
+struct Cursor<const N: usize> {
+    pos: usize,
+    chunk: usize,
+    offset: usize,
+}
+
+impl<const N: usize> Cursor<N> {
+    #[inline]
+    fn new(pos: usize) -> Self {
+        Cursor {
+            pos,
+            chunk: pos / N,
+            offset: pos % N,
+        }
+    }
+
+    #[inline]
+    fn step_forward(&mut self) {
+        self.pos += 1;
+        self.offset += 1;
+        if self.offset == N {
+            self.offset = 0;
+            self.chunk += 1;
+        }
+    }
+
+    #[inline]
+    fn step_back(&mut self) {
+        self.pos -= 1;
+        if self.offset == 0 {
+            self.offset = N;
+            self.chunk -= 1;
+        }
+        self.offset -= 1;
+    }
+
+    /// Moves `n` positions forward, at most to the end of the current chunk.
+    #[inline]
+    fn forward(mut self, n: usize) -> Self {
+        debug_assert!(self.offset + n <= N);
+        self.pos += n;
+        self.offset += n;
+        if self.offset == N {
+            self.offset = 0;
+            self.chunk += 1;
+        }
+        self
+    }
+
+    /// Moves `n` positions back, at most to the start of the current chunk.
+    #[inline]
+    fn back(mut self, n: usize) -> Self {
+        debug_assert!(n <= self.offset);
+        self.pos -= n;
+        self.offset -= n;
+        self
+    }
+}
 
 /// Implementation of in-place sorting for ChunkedVec.
 ///
 /// Sorting is performed without allocating any memory, matching the guarantee
-/// of [`slice::sort_unstable`]. The algorithm is an introsort over logical
-/// indices: quicksort partitioning for ranges that span multiple chunks, the
-/// standard library's slice sort for any range that lies entirely within one
-/// chunk, and a heapsort fallback that bounds the worst case at *O*(*n* log *n*).
+/// of [`slice::sort_unstable`]. The algorithm is a pattern-defeating quicksort
+/// (pdqsort) over logical indices:
+/// - ranges that lie entirely within one chunk are handed to the standard
+///   library's slice sort, and short ranges spanning chunks are insertion
+///   sorted;
+/// - partitioning walks two chunk cursors towards each other, so scanning
+///   and swapping never divide by `N`;
+/// - when the pivot equals the pivot of an enclosing partition, all elements
+///   equal to it are gathered in one linear pass, so inputs with many
+///   duplicates take *O*(*n* log *k*) for *k* distinct values;
+/// - ranges that look sorted (or reverse-sorted) are finished with a few
+///   insertion steps, and unbalanced partitions shuffle a few elements and
+///   eventually fall back to heapsort, bounding the worst case at
+///   *O*(*n* log *n*).
+///
+/// The same partitioning drives quickselect for `select_nth_unstable*` and
+/// `k_smallest`.
 ///
 /// Elements are only ever swapped in place, so every slot stays initialized at
 /// all times: if a caller-supplied comparator panics, the vector is left in a
@@ -19,9 +98,8 @@ impl<T, const N: usize> ChunkedVec<T, N> {
     /// (i.e., does not allocate).
     ///
     /// # Complexity
-    /// Worst case *O*(*n* log *n*). Best case *O*(*n*) when the vector fits
-    /// in a single chunk; *O*(*n* log(*n*/*N*)) otherwise, even for
-    /// already-sorted input.
+    /// Worst case *O*(*n* log *n*). Best case *O*(*n*) for already-sorted,
+    /// reverse-sorted or all-equal input.
     ///
     /// # Examples
     /// ```
@@ -45,12 +123,14 @@ impl<T, const N: usize> ChunkedVec<T, N> {
     /// (i.e., does not allocate).
     ///
     /// If `compare` panics, the vector is left as a valid permutation of its
-    /// former contents.
+    /// former contents. This holds because `compare` is only ever called on
+    /// elements that are in place: the sort moves elements with swaps, never
+    /// by taking one out and leaving a hole, so no guard is needed to restore
+    /// the vector while unwinding.
     ///
     /// # Complexity
-    /// Worst case *O*(*n* log *n*). Best case *O*(*n*) when the vector fits
-    /// in a single chunk; *O*(*n* log(*n*/*N*)) otherwise, even for
-    /// already-sorted input.
+    /// Worst case *O*(*n* log *n*). Best case *O*(*n*) for already-sorted,
+    /// reverse-sorted or all-equal input.
     ///
     /// # Examples
     /// ```
@@ -60,15 +140,11 @@ impl<T, const N: usize> ChunkedVec<T, N> {
     /// v.sort_unstable_by(|a, b| b.cmp(a));
     /// assert_eq!(v, [5, 4, 3, 2, 1]);
     /// ```
-    pub fn sort_unstable_by<F>(&mut self, mut compare: F)
+    pub fn sort_unstable_by<F>(&mut self, compare: F)
     where
         F: FnMut(&T, &T) -> Ordering,
     {
-        if self.len < 2 {
-            return;
-        }
-        let depth_limit = 2 * (usize::BITS - self.len.leading_zeros()) as usize;
-        self.introsort(0, self.len, depth_limit, &mut compare);
+        self.sort_range_unstable_by(0, self.len, compare);
     }
 
     /// Sorts the vector with a key extraction function, but might not
@@ -78,9 +154,8 @@ impl<T, const N: usize> ChunkedVec<T, N> {
     /// (i.e., does not allocate).
     ///
     /// # Complexity
-    /// Worst case *O*(*n* log *n*). Best case *O*(*n*) when the vector fits
-    /// in a single chunk; *O*(*n* log(*n*/*N*)) otherwise, even for
-    /// already-sorted input.
+    /// Worst case *O*(*n* log *n*). Best case *O*(*n*) for already-sorted,
+    /// reverse-sorted or all-equal input.
     ///
     /// # Examples
     /// ```
@@ -98,6 +173,209 @@ impl<T, const N: usize> ChunkedVec<T, N> {
         self.sort_unstable_by(|a, b| f(a).cmp(&f(b)));
     }
 
+    /// Sorts the logical range `[lo, hi)` with a comparison function, but
+    /// might not preserve the order of equal elements.
+    ///
+    /// The range must lie within `self.len`.
+    pub(crate) fn sort_range_unstable_by<F>(&mut self, lo: usize, hi: usize, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        debug_assert!(lo <= hi && hi <= self.len);
+        let len = hi - lo;
+        if len < 2 {
+            return;
+        }
+        let limit = (usize::BITS - len.leading_zeros()) as usize;
+        self.pdqsort(lo, hi, None, limit, &mut compare);
+    }
+
+    /// Reorders the vector such that the element at `index` is at its final
+    /// sorted position, and returns a mutable reference to it.
+    ///
+    /// Every element before `index` is less than or equal to it and every
+    /// element after `index` is greater than or equal to it; the two sides
+    /// are otherwise in no particular order and can be reached with
+    /// [`slice_mut`](ChunkedVec::slice_mut). This reordering is unstable and
+    /// in-place (i.e., does not allocate).
+    ///
+    /// # Panics
+    /// Panics when `index >= len()`.
+    ///
+    /// # Complexity
+    /// Expected *O*(*n*); worst case *O*(*n* log *n*).
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let mut v = chunked_vec![-5i32, 4, 2, -3, 1];
+    ///
+    /// // Find the median
+    /// let median = *v.select_nth_unstable(2);
+    /// assert_eq!(median, 1);
+    /// assert!(v.slice(..2).iter().all(|&x| x <= median));
+    /// assert!(v.slice(3..).iter().all(|&x| x >= median));
+    /// ```
+    pub fn select_nth_unstable(&mut self, index: usize) -> &mut T
+    where
+        T: Ord,
+    {
+        self.select_nth_unstable_by(index, T::cmp)
+    }
+
+    /// Reorders the vector with a comparator function such that the element
+    /// at `index` is at its final sorted position, and returns a mutable
+    /// reference to it.
+    ///
+    /// See [`select_nth_unstable`](ChunkedVec::select_nth_unstable) for the
+    /// guarantees about the elements on either side. If `compare` panics,
+    /// the vector is left as a valid permutation of its former contents.
+    ///
+    /// # Panics
+    /// Panics when `index >= len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let mut v = chunked_vec![-5i32, 4, 2, -3, 1];
+    ///
+    /// // The second largest element
+    /// assert_eq!(*v.select_nth_unstable_by(1, |a, b| b.cmp(a)), 2);
+    /// ```
+    pub fn select_nth_unstable_by<F>(&mut self, index: usize, mut compare: F) -> &mut T
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let len = self.len;
+        if index >= len {
+            panic!("partition_at_index index {index} greater than length of slice {len}");
+        }
+        self.select_range(0, len, index, &mut compare);
+        // Safety: index < len.
+        unsafe { self.get_unchecked_mut(index) }
+    }
+
+    /// Reorders the vector with a key extraction function such that the
+    /// element at `index` is at its final sorted position, and returns a
+    /// mutable reference to it.
+    ///
+    /// See [`select_nth_unstable`](ChunkedVec::select_nth_unstable) for the
+    /// guarantees about the elements on either side.
+    ///
+    /// # Panics
+    /// Panics when `index >= len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let mut v = chunked_vec![-5i32, 4, 1, -3, 2];
+    ///
+    /// assert_eq!(*v.select_nth_unstable_by_key(4, |k| k.abs()), -5);
+    /// ```
+    pub fn select_nth_unstable_by_key<K, F>(&mut self, index: usize, mut f: F) -> &mut T
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.select_nth_unstable_by(index, |a, b| f(a).cmp(&f(b)))
+    }
+
+    /// Moves the `k` smallest elements to the front of the vector in sorted
+    /// order, and returns them as a slice.
+    ///
+    /// The remaining elements are left in no particular order. This is
+    /// cheaper than sorting the whole vector when `k` is small, and runs in
+    /// place (i.e., does not allocate). If `k >= len()`, the whole vector is
+    /// sorted.
+    ///
+    /// # Complexity
+    /// Expected *O*(*n* + *k* log *k*).
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let mut v = chunked_vec![9, 4, 7, 1, 8, 2, 6, 3, 5];
+    ///
+    /// let smallest = v.k_smallest(3);
+    /// assert!(smallest.iter().eq(&[1, 2, 3]));
+    /// ```
+    pub fn k_smallest(&mut self, k: usize) -> ChunkedSlice<'_, T, N>
+    where
+        T: Ord,
+    {
+        let k = k.min(self.len);
+        if k > 0 && k < self.len {
+            // Everything before position k - 1 is now no greater than it, so
+            // only that prefix is left to sort.
+            self.select_range(0, self.len, k - 1, &mut T::cmp);
+            self.sort_range_unstable_by(0, k - 1, T::cmp);
+        } else {
+            self.sort_unstable();
+        }
+        self.slice(..k)
+    }
+
+    /// Quickselect over the logical range `[lo, hi)`, placing the element
+    /// that belongs at `index` there and partitioning the rest around it.
+    ///
+    /// Uses the same pivot selection and partitioning as
+    /// [`pdqsort`](Self::pdqsort); a heapsort fallback bounds the worst case.
+    fn select_range<F>(&mut self, mut lo: usize, mut hi: usize, index: usize, compare: &mut F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        debug_assert!(lo <= index && index < hi && hi <= self.len);
+        let mut limit = 2 * (usize::BITS - (hi - lo).leading_zeros()) as usize;
+        let mut pred = None;
+        loop {
+            if hi - lo <= 1 {
+                return;
+            }
+            if lo / N == (hi - 1) / N {
+                // Safety: [lo, hi) is within self.len and in a single chunk.
+                unsafe { self.range_slice_mut(lo, hi) }
+                    .select_nth_unstable_by(index - lo, |a, b| compare(a, b));
+                return;
+            }
+            if hi - lo <= MAX_INSERTION {
+                self.insertion_sort(lo, hi, compare);
+                return;
+            }
+            if limit == 0 {
+                self.heapsort(lo, hi, compare);
+                return;
+            }
+            limit -= 1;
+
+            let (pivot, _) = self.choose_pivot(lo, hi, compare);
+            // Safety: lo <= pivot < hi <= len.
+            unsafe { self.swap_unchecked(lo, pivot) };
+
+            if let Some(pred) = pred {
+                if self.cmp_at(pred, lo, compare) != Ordering::Less {
+                    // The pivot equals the predecessor, so it is the minimum
+                    // of the range: peel off everything equal to it.
+                    let mid = self.partition_equal(lo, hi, compare);
+                    if index < mid {
+                        return;
+                    }
+                    lo = mid;
+                    continue;
+                }
+            }
+
+            let (mid, _) = self.partition(lo, hi, compare);
+            match index.cmp(&mid) {
+                Ordering::Equal => return,
+                Ordering::Less => hi = mid,
+                Ordering::Greater => {
+                    lo = mid + 1;
+                    pred = Some(mid);
+                }
+            }
+        }
+    }
+
     /// Returns the logical range `[lo, hi)` as a contiguous mutable slice.
     ///
     /// # Safety
@@ -113,47 +391,83 @@ impl<T, const N: usize> ChunkedVec<T, N> {
     /// # Safety
     /// Both `a` and `b` must be below `self.len`.
     #[inline]
-    unsafe fn swap_unchecked(&mut self, a: usize, b: usize) {
-        debug_assert!(a < self.len && b < self.len);
-        if a == b {
-            return;
-        }
+    pub(crate) unsafe fn swap_unchecked(&mut self, a: usize, b: usize) {
+        self.swap_at(Cursor::new(a), Cursor::new(b));
+    }
 
-        let (a_chunk, a_off) = self.chunk_and_offset(a);
-        let (b_chunk, b_off) = self.chunk_and_offset(b);
-        // Safety: both indices are in bounds, so both slots are initialized;
-        // distinct indices never overlap. Same-chunk pointers must be derived
-        // from one borrow of the chunk to keep both provenances valid.
-        if a_chunk == b_chunk {
-            let base = self.get_chunk_mut_ptr(a_chunk);
-            std::ptr::swap_nonoverlapping(base.add(a_off), base.add(b_off), 1);
+    /// Swaps the elements at two cursors.
+    ///
+    /// # Safety
+    /// Both cursors must be below `self.len`.
+    #[inline]
+    unsafe fn swap_at(&mut self, a: Cursor<N>, b: Cursor<N>) {
+        debug_assert!(a.pos < self.len && b.pos < self.len);
+        // Safety: both positions are in bounds, so both slots are
+        // initialized; distinct positions never overlap. Same-chunk pointers
+        // must be derived from one borrow of the chunk to keep both
+        // provenances valid.
+        if a.chunk == b.chunk {
+            if a.offset == b.offset {
+                return;
+            }
+            let base = self.get_chunk_mut_ptr(a.chunk);
+            ptr::swap_nonoverlapping(base.add(a.offset), base.add(b.offset), 1);
         } else {
-            let a_ptr = self.get_elem_mut_ptr(a_chunk, a_off);
-            let b_ptr = self.get_elem_mut_ptr(b_chunk, b_off);
-            std::ptr::swap_nonoverlapping(a_ptr, b_ptr, 1);
+            let a_ptr = self.get_elem_mut_ptr(a.chunk, a.offset);
+            let b_ptr = self.get_elem_mut_ptr(b.chunk, b.offset);
+            ptr::swap_nonoverlapping(a_ptr, b_ptr, 1);
         }
     }
 
     /// Compares the elements at two in-bounds indices.
     #[inline]
-    fn cmp_at<F>(&self, a: usize, b: usize, compare: &mut F) -> Ordering
+    pub(crate) fn cmp_at<F>(&self, a: usize, b: usize, compare: &mut F) -> Ordering
     where
         F: FnMut(&T, &T) -> Ordering,
     {
         // Safety: callers only pass indices below self.len.
-        compare( unsafe { self.get_unchecked(a) }, unsafe { self.get_unchecked(b) } )
+        let (a, b) = unsafe { (self.get_unchecked(a), self.get_unchecked(b)) };
+        compare(a, b)
     }
 
-    /// Sorts the logical range `[lo, hi)`.
+    /// Returns whether the element at `a` is less than the element at `b`.
+    #[inline]
+    fn is_less_at<F>(&self, a: Cursor<N>, b: Cursor<N>, compare: &mut F) -> bool
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        // Safety: callers only pass cursors below self.len.
+        unsafe {
+            let a = &*self.get_elem_ptr(a.chunk, a.offset);
+            let b = &*self.get_elem_ptr(b.chunk, b.offset);
+            compare(a, b) == Ordering::Less
+        }
+    }
+
+    /// Sorts the logical range `[lo, hi)` with pattern-defeating quicksort.
+    ///
+    /// `pred` is the index of an element known to be no greater than any
+    /// element in the range (the pivot of an enclosing partition), and
+    /// `limit` the number of unbalanced partitions allowed before switching
+    /// to heapsort.
     ///
     /// Recurses only into the smaller partition and loops on the larger one,
     /// bounding stack depth at *O*(log *n*).
-    fn introsort<F>(&mut self, mut lo: usize, mut hi: usize, mut depth: usize, compare: &mut F)
-    where
+    fn pdqsort<F>(
+        &mut self,
+        mut lo: usize,
+        mut hi: usize,
+        mut pred: Option<usize>,
+        mut limit: usize,
+        compare: &mut F,
+    ) where
         F: FnMut(&T, &T) -> Ordering,
     {
+        let mut was_balanced = true;
+        let mut was_partitioned = true;
         loop {
-            if hi - lo <= 1 {
+            let len = hi - lo;
+            if len <= 1 {
                 return;
             }
             if lo / N == (hi - 1) / N {
@@ -163,58 +477,401 @@ impl<T, const N: usize> ChunkedVec<T, N> {
                 unsafe { self.range_slice_mut(lo, hi) }.sort_unstable_by(|a, b| compare(a, b));
                 return;
             }
-            if depth == 0 {
+            if len <= MAX_INSERTION {
+                self.insertion_sort(lo, hi, compare);
+                return;
+            }
+            if limit == 0 {
                 self.heapsort(lo, hi, compare);
                 return;
             }
-            depth -= 1;
 
-            let p = self.partition(lo, hi, compare);
-            if p - lo < hi - (p + 1) {
-                self.introsort(lo, p, depth, compare);
-                lo = p + 1;
+            // The last partition was badly unbalanced: shuffle a few
+            // elements to defeat adversarial patterns.
+            if !was_balanced {
+                self.break_patterns(lo, hi);
+                limit -= 1;
+            }
+
+            let (pivot, likely_sorted) = self.choose_pivot(lo, hi, compare);
+
+            // The range looks sorted and the last partition needed no swaps:
+            // try to finish it with a few insertion steps.
+            if was_balanced
+                && was_partitioned
+                && likely_sorted
+                && self.partial_insertion_sort(lo, hi, compare)
+            {
+                return;
+            }
+
+            // Safety: lo <= pivot < hi <= len.
+            unsafe { self.swap_unchecked(lo, pivot) };
+
+            if let Some(pred) = pred {
+                if self.cmp_at(pred, lo, compare) != Ordering::Less {
+                    // The pivot equals the predecessor, so it is the minimum
+                    // of the range: everything equal to it is already in
+                    // place once gathered at the front.
+                    lo = self.partition_equal(lo, hi, compare);
+                    continue;
+                }
+            }
+
+            let (mid, partitioned) = self.partition(lo, hi, compare);
+            let (left_len, right_len) = (mid - lo, hi - mid - 1);
+            was_balanced = left_len.min(right_len) >= len / 8;
+            was_partitioned = partitioned;
+
+            if left_len < right_len {
+                self.pdqsort(lo, mid, pred, limit, compare);
+                lo = mid + 1;
+                pred = Some(mid);
             } else {
-                self.introsort(p + 1, hi, depth, compare);
-                hi = p;
+                self.pdqsort(mid + 1, hi, Some(mid), limit, compare);
+                hi = mid;
             }
         }
     }
 
-    /// Partitions `[lo, hi)` around a median-of-3 pivot and returns the
+    /// Picks a pivot index in `[lo, hi)` from a median of three, or a
+    /// median of medians for longer ranges, without moving any elements.
+    ///
+    /// Also returns whether the range is likely already sorted. A range that
+    /// looks reverse-sorted is reversed first.
+    fn choose_pivot<F>(&mut self, lo: usize, hi: usize, compare: &mut F) -> (usize, bool)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        const SHORTEST_MEDIAN_OF_MEDIANS: usize = 50;
+        const MAX_SWAPS: usize = 4 * 3;
+
+        let len = hi - lo;
+        debug_assert!(len >= 8);
+        let mut a = lo + len / 4;
+        let mut b = lo + len / 4 * 2;
+        let mut c = lo + len / 4 * 3;
+        let mut swaps = 0;
+
+        {
+            let this = &*self;
+            // Orders two indices so that the element at `a` is not greater.
+            let mut sort2 = |a: &mut usize, b: &mut usize| {
+                if this.cmp_at(*b, *a, compare) == Ordering::Less {
+                    std::mem::swap(a, b);
+                    swaps += 1;
+                }
+            };
+            let mut sort3 = |a: &mut usize, b: &mut usize, c: &mut usize| {
+                sort2(a, b);
+                sort2(b, c);
+                sort2(a, b);
+            };
+
+            if len >= SHORTEST_MEDIAN_OF_MEDIANS {
+                // Replaces `a` with the median of its neighbourhood.
+                let mut sort_adjacent = |a: &mut usize| {
+                    let tmp = *a;
+                    sort3(&mut (tmp - 1), a, &mut (tmp + 1));
+                };
+                sort_adjacent(&mut a);
+                sort_adjacent(&mut b);
+                sort_adjacent(&mut c);
+            }
+            sort3(&mut a, &mut b, &mut c);
+        }
+
+        if swaps < MAX_SWAPS {
+            (b, swaps == 0)
+        } else {
+            // Every comparison disagreed: the range is likely descending.
+            self.reverse_range(lo, hi);
+            (lo + (hi - 1 - b), true)
+        }
+    }
+
+    /// Partitions `[lo, hi)` around the pivot at `lo` and returns the
     /// pivot's final position.
-    fn partition<F>(&mut self, lo: usize, hi: usize, compare: &mut F) -> usize
+    ///
+    /// Elements less than the pivot end up before it, the others after it.
+    /// Also returns whether the range was already partitioned, in which case
+    /// no element other than the pivot was moved.
+    fn partition<F>(&mut self, lo: usize, hi: usize, compare: &mut F) -> (usize, bool)
     where
         F: FnMut(&T, &T) -> Ordering,
     {
-        let mid = lo + (hi - lo) / 2;
-        let last = hi - 1;
+        let pivot = Cursor::new(lo);
+        // Skip the prefix that is already less than the pivot and the suffix
+        // that is not.
+        let mut l = Cursor::new(lo + 1);
+        let mut r = Cursor::new(hi - 1);
+        while l.pos <= r.pos && self.is_less_at(l, pivot, compare) {
+            l.step_forward();
+        }
+        while l.pos <= r.pos && !self.is_less_at(r, pivot, compare) {
+            r.step_back();
+        }
+        let was_partitioned = l.pos > r.pos;
 
-        // Safety (all swaps below): lo <= mid <= i <= j <= last < hi <= len.
-        unsafe {
-            // Median-of-3: order the elements at lo, mid, last, then park
-            // the median at `last` as the pivot.
-            if self.cmp_at(mid, lo, compare) == Ordering::Less {
-                self.swap_unchecked(lo, mid);
-            }
-            if self.cmp_at(last, lo, compare) == Ordering::Less {
-                self.swap_unchecked(lo, last);
-            }
-            if self.cmp_at(last, mid, compare) == Ordering::Less {
-                self.swap_unchecked(mid, last);
-            }
-            self.swap_unchecked(mid, last);
-
-            // Lomuto partition against the pivot, which stays at `last`
-            // until the final swap places it at its sorted position.
-            let mut i = lo;
-            for j in lo..last {
-                if self.cmp_at(j, last, compare) != Ordering::Greater {
-                    self.swap_unchecked(i, j);
-                    i += 1;
+        let mid = if was_partitioned {
+            l.pos - 1
+        } else {
+            r.step_forward();
+            self.partition_in_blocks(lo, l, r, compare) - 1
+        };
+        // Safety: lo <= mid < hi <= len.
+        unsafe { self.swap_unchecked(lo, mid) };
+        (mid, was_partitioned)
+    }
+
+    /// Partitions `[l, r)` around the pivot at index `pivot`, which lies
+    /// outside the range, and returns the first position whose element is
+    /// not less than the pivot.
+    ///
+    /// The range is consumed in blocks from both ends. Each block lies within
+    /// a single chunk, so it is scanned through one base pointer, recording
+    /// the offsets of misplaced elements without branching on the outcome of
+    /// each comparison. Misplaced elements from the left and right blocks are
+    /// then swapped pairwise.
+    fn partition_in_blocks<F>(
+        &mut self,
+        pivot: usize,
+        mut l: Cursor<N>,
+        mut r: Cursor<N>,
+        compare: &mut F,
+    ) -> usize
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        const BLOCK: usize = 128;
+
+        // Offsets of elements not less than the pivot in the left block, and
+        // of elements less than the pivot in the right block (counted
+        // backwards from its last element). Only `start..end` is pending.
+        let mut offsets_l = [0u8; BLOCK];
+        let mut offsets_r = [0u8; BLOCK];
+        let (mut start_l, mut end_l) = (0, 0);
+        let (mut start_r, mut end_r) = (0, 0);
+        // The first element of the left block and the last element of the
+        // right block.
+        let mut block_l = l;
+        let mut block_r = l;
+
+        loop {
+            if start_l == end_l && l.pos < r.pos {
+                let len = BLOCK.min(N - l.offset).min(r.pos - l.pos);
+                start_l = 0;
+                end_l = 0;
+                // Safety: `[l, l + len)` lies within one chunk and below
+                // self.len, and the pivot is outside it.
+                unsafe {
+                    let base = self.get_elem_ptr(l.chunk, l.offset);
+                    let pivot = self.get_unchecked(pivot);
+                    for i in 0..len {
+                        *offsets_l.get_unchecked_mut(end_l) = i as u8;
+                        end_l += (compare(&*base.add(i), pivot) != Ordering::Less) as usize;
+                    }
                 }
+                block_l = l;
+                l = l.forward(len);
+            }
+
+            if start_r == end_r && l.pos < r.pos {
+                let mut last = r;
+                last.step_back();
+                let len = BLOCK.min(last.offset + 1).min(r.pos - l.pos);
+                start_r = 0;
+                end_r = 0;
+                // Safety: `(last - len, last]` lies within one chunk and
+                // below self.len, and the pivot is outside it.
+                unsafe {
+                    let base = self.get_elem_ptr(last.chunk, last.offset);
+                    let pivot = self.get_unchecked(pivot);
+                    for i in 0..len {
+                        *offsets_r.get_unchecked_mut(end_r) = i as u8;
+                        end_r += (compare(&*base.sub(i), pivot) == Ordering::Less) as usize;
+                    }
+                }
+                block_r = last;
+                r = last.back(len - 1);
+            }
+
+            let count = (end_l - start_l).min(end_r - start_r);
+            for k in 0..count {
+                let a = block_l.forward(offsets_l[start_l + k] as usize);
+                let b = block_r.back(offsets_r[start_r + k] as usize);
+                // Safety: both positions were scanned, so they are in bounds.
+                unsafe { self.swap_at(a, b) };
+            }
+            start_l += count;
+            start_r += count;
+
+            // Once nothing is left to scan, at most one block still has
+            // misplaced elements.
+            if l.pos == r.pos && (start_l == end_l || start_r == end_r) {
+                break;
+            }
+        }
+
+        // Move the leftover misplaced elements to the boundary, starting
+        // with the ones closest to it.
+        // Safety (all swaps): every position involved was scanned.
+        if start_l < end_l {
+            while start_l < end_l {
+                end_l -= 1;
+                r.step_back();
+                unsafe { self.swap_at(block_l.forward(offsets_l[end_l] as usize), r) };
+            }
+            r.pos
+        } else {
+            while start_r < end_r {
+                end_r -= 1;
+                unsafe { self.swap_at(block_r.back(offsets_r[end_r] as usize), l) };
+                l.step_forward();
+            }
+            l.pos
+        }
+    }
+
+    /// Partitions `[lo, hi)` into elements equal to the pivot at `lo`
+    /// followed by elements greater than it, and returns the start of the
+    /// second group.
+    ///
+    /// Assumes no element in the range is less than the pivot.
+    fn partition_equal<F>(&mut self, lo: usize, hi: usize, compare: &mut F) -> usize
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let pivot = Cursor::new(lo);
+        let mut l = Cursor::new(lo + 1);
+        let mut r = Cursor::new(hi - 1);
+        loop {
+            while l.pos <= r.pos && !self.is_less_at(pivot, l, compare) {
+                l.step_forward();
+            }
+            while l.pos <= r.pos && self.is_less_at(pivot, r, compare) {
+                r.step_back();
+            }
+            if l.pos >= r.pos {
+                return l.pos;
+            }
+            // Safety: lo < l < r < hi <= len.
+            unsafe { self.swap_at(l, r) };
+            l.step_forward();
+            r.step_back();
+        }
+    }
+
+    /// Sorts `[lo, hi)` by inserting each element into the sorted prefix.
+    fn insertion_sort<F>(&mut self, lo: usize, hi: usize, compare: &mut F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut i = Cursor::new(lo + 1);
+        while i.pos < hi {
+            self.shift_tail(lo, i, compare);
+            i.step_forward();
+        }
+    }
+
+    /// Moves the element at `end` backwards until `[lo, end]` is sorted,
+    /// assuming `[lo, end)` already is.
+    fn shift_tail<F>(&mut self, lo: usize, end: Cursor<N>, compare: &mut F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut j = end;
+        while j.pos > lo {
+            let mut prev = j;
+            prev.step_back();
+            if !self.is_less_at(j, prev, compare) {
+                return;
+            }
+            // Safety: lo <= prev < j < hi <= len.
+            unsafe { self.swap_at(j, prev) };
+            j = prev;
+        }
+    }
+
+    /// Moves the element at `start` forwards until `[start, hi)` is sorted,
+    /// assuming `(start, hi)` already is.
+    fn shift_head<F>(&mut self, start: Cursor<N>, hi: usize, compare: &mut F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut j = start;
+        loop {
+            let mut next = j;
+            next.step_forward();
+            if next.pos >= hi || !self.is_less_at(next, j, compare) {
+                return;
+            }
+            // Safety: start <= j < next < hi <= len.
+            unsafe { self.swap_at(j, next) };
+            j = next;
+        }
+    }
+
+    /// Partially sorts `[lo, hi)` by fixing up to a few out-of-order
+    /// adjacent pairs, and returns whether the range ended up sorted.
+    fn partial_insertion_sort<F>(&mut self, lo: usize, hi: usize, compare: &mut F) -> bool
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        const MAX_STEPS: usize = 5;
+        const SHORTEST_SHIFTING: usize = 50;
+
+        let mut i = Cursor::new(lo + 1);
+        for _ in 0..MAX_STEPS {
+            let mut prev = i;
+            prev.step_back();
+            while i.pos < hi && !self.is_less_at(i, prev, compare) {
+                prev = i;
+                i.step_forward();
+            }
+            if i.pos == hi {
+                return true;
+            }
+            // Not worth shifting on short ranges; let quicksort handle them.
+            if hi - lo < SHORTEST_SHIFTING {
+                return false;
+            }
+
+            // Safety: lo <= prev < i < hi <= len.
+            unsafe { self.swap_at(prev, i) };
+            self.shift_tail(lo, prev, compare);
+            self.shift_head(i, hi, compare);
+        }
+        false
+    }
+
+    /// Swaps three elements near the middle of `[lo, hi)` with
+    /// pseudo-randomly chosen ones to break up patterns that cause
+    /// unbalanced partitions.
+    fn break_patterns(&mut self, lo: usize, hi: usize) {
+        let len = hi - lo;
+        if len < 8 {
+            return;
+        }
+
+        // Xorshift, seeded with the length for reproducible behaviour.
+        let mut seed = len as u64;
+        let mut next = || {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed as usize
+        };
+
+        let modulus = len.next_power_of_two();
+        let pos = lo + len / 4 * 2;
+        for i in 0..3 {
+            let mut other = next() & (modulus - 1);
+            if other >= len {
+                other -= len;
             }
-            self.swap_unchecked(i, last);
-            i
+            // Safety: both positions lie within [lo, hi).
+            unsafe { self.swap_unchecked(pos - 1 + i, lo + other) };
         }
     }
 
@@ -245,8 +902,7 @@ impl<T, const N: usize> ChunkedVec<T, N> {
             if child >= n {
                 return;
             }
-            if child + 1 < n && self.cmp_at(lo + child, lo + child + 1, compare) == Ordering::Less
-            {
+            if child + 1 < n && self.cmp_at(lo + child, lo + child + 1, compare) == Ordering::Less {
                 child += 1;
             }
             if self.cmp_at(lo + root, lo + child, compare) != Ordering::Less {
@@ -321,7 +977,7 @@ mod tests {
 
     #[test]
     fn sort_all_duplicates() {
-        // The Lomuto worst case: forces the heapsort fallback.
+        // Handled in linear time by gathering elements equal to the pivot.
         check_sort::<3>(&[7; 40]);
     }
 
@@ -421,4 +1077,163 @@ mod tests {
         drop(vec);
         assert_eq!(SORT_PANIC_DROP_COUNT.load(Ordering::SeqCst), len);
     }
+
+    fn check_select<const N: usize>(data: &[i32]) {
+        let mut expected = data.to_vec();
+        expected.sort_unstable();
+
+        for index in 0..data.len() {
+            let mut vec: ChunkedVec<i32, N> = ChunkedVecSized::new();
+            vec.extend(data.iter().copied());
+
+            let nth = *vec.select_nth_unstable(index);
+            assert_eq!(nth, expected[index]);
+            assert_eq!(vec[index], nth);
+            assert!(vec.iter().take(index).all(|&x| x <= nth));
+            assert!(vec.iter().skip(index + 1).all(|&x| x >= nth));
+
+            let mut sorted: Vec<i32> = vec.iter().copied().collect();
+            sorted.sort_unstable();
+            assert_eq!(sorted, expected);
+        }
+    }
+
+    #[test]
+    fn select_nth_across_chunks() {
+        let data = [5, -1, 3, 9, 0, -7, 2, 8, 1, 4, -3, 6, 7, -2, 3, 3];
+        check_select::<1>(&data);
+        check_select::<3>(&data);
+        check_select::<4>(&data);
+        check_select::<64>(&data);
+        check_select::<3>(&[7; 20]);
+        check_select::<3>(&(0..20).collect::<Vec<_>>());
+        check_select::<3>(&(0..20).rev().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn select_nth_by_and_by_key() {
+        let mut vec: ChunkedVec<i32, 3> = ChunkedVecSized::new();
+        vec.extend([5, 1, 4, 2, 3, 7, 6]);
+        assert_eq!(*vec.select_nth_unstable_by(0, |a, b| b.cmp(a)), 7);
+
+        let mut vec: ChunkedVec<(i32, &str), 2> = ChunkedVecSized::new();
+        vec.extend([(3, "c"), (1, "a"), (4, "d"), (2, "b"), (5, "e")]);
+        let nth = vec.select_nth_unstable_by_key(3, |&(num, _)| num);
+        assert_eq!(*nth, (4, "d"));
+        nth.1 = "changed";
+        assert_eq!(vec[3], (4, "changed"));
+    }
+
+    #[test]
+    #[should_panic(expected = "partition_at_index index 5 greater than length of slice 5")]
+    fn select_nth_out_of_bounds() {
+        let mut vec: ChunkedVec<i32, 2> = (0..5).collect();
+        vec.select_nth_unstable(5);
+    }
+
+    #[test]
+    fn k_smallest_sorted_prefix() {
+        let data: Vec<i32> = (0..1_000u64)
+            .map(|i| (i.wrapping_mul(2654435761) % 500) as i32)
+            .collect();
+        let mut expected = data.clone();
+        expected.sort_unstable();
+
+        for k in [0, 1, 2, 10, 63, 64, 65, 999, 1_000, 5_000] {
+            let mut vec: ChunkedVec<i32, 8> = ChunkedVecSized::new();
+            vec.extend(data.iter().copied());
+
+            let smallest = vec.k_smallest(k);
+            let k = k.min(data.len());
+            assert_eq!(smallest.len(), k);
+            assert!(smallest.iter().eq(expected[..k].iter()));
+
+            let mut all: Vec<i32> = vec.iter().copied().collect();
+            all.sort_unstable();
+            assert_eq!(all, expected);
+        }
+    }
+
+    /// Inputs that exercise the pattern detection and the equal-element
+    /// partitioning, built from a deterministic xorshift generator.
+    fn patterns(len: usize) -> Vec<Vec<i32>> {
+        let mut seed = 0x2545_f491_4f6c_dd1d_u64;
+        let mut random = || {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed
+        };
+        let n = len as i32;
+        let mut sorted_with_swaps: Vec<i32> = (0..n).collect();
+        for _ in 0..3.min(len / 2) {
+            let (a, b) = (random() as usize % len, random() as usize % len);
+            sorted_with_swaps.swap(a, b);
+        }
+        vec![
+            (0..len).map(|_| random() as i32).collect(),
+            (0..len).map(|_| (random() % 4) as i32).collect(),
+            (0..n).collect(),
+            (0..n).rev().collect(),
+            (0..n).map(|i| i % 17).collect(),
+            (0..n).map(|i| if i < n / 2 { i } else { n - i }).collect(),
+            (0..n).map(|i| if i % 2 == 0 { i } else { -i }).collect(),
+            sorted_with_swaps,
+            vec![1; len],
+        ]
+    }
+
+    fn check_patterns<const N: usize>() {
+        for len in [0, 1, 2, 19, 20, 21, 49, 50, 51, 100, 1_000, 5_000] {
+            for data in patterns(len) {
+                check_sort::<N>(&data);
+            }
+        }
+    }
+
+    #[test]
+    fn sort_patterns_match_std() {
+        check_patterns::<1>();
+        check_patterns::<2>();
+        check_patterns::<3>();
+        check_patterns::<16>();
+        check_patterns::<64>();
+        check_patterns::<256>();
+    }
+
+    /// Counts the comparisons `sort_unstable` makes on `data`.
+    fn count_comparisons<const N: usize>(data: impl IntoIterator<Item = i32>) -> usize {
+        let mut vec: ChunkedVec<i32, N> = ChunkedVecSized::new();
+        vec.extend(data);
+        let mut calls = 0;
+        vec.sort_unstable_by(|a, b| {
+            calls += 1;
+            a.cmp(b)
+        });
+        assert!(vec.iter().zip(vec.iter().skip(1)).all(|(a, b)| a <= b));
+        calls
+    }
+
+    #[test]
+    fn sort_linear_on_presorted_input() {
+        let len = 10_000;
+        // Chunks of 1 make every range span chunks, so nothing is handed
+        // to the slice sort.
+        assert!(count_comparisons::<1>(vec![7; len]) < 4 * len);
+        assert!(count_comparisons::<1>(0..len as i32) < 4 * len);
+        assert!(count_comparisons::<1>((0..len as i32).rev()) < 4 * len);
+        assert!(count_comparisons::<4>((0..len as i32).map(|i| i % 3)) < 8 * len);
+    }
+
+    #[test]
+    fn select_nth_many_duplicates() {
+        let data: Vec<i32> = (0..500).map(|i| i % 3).collect();
+        let mut vec: ChunkedVec<i32, 4> = ChunkedVecSized::new();
+        vec.extend(data.iter().copied());
+        assert_eq!(*vec.select_nth_unstable(250), 1);
+
+        let mut vec: ChunkedVec<i32, 4> = ChunkedVecSized::new();
+        vec.extend([5; 300]);
+        assert_eq!(*vec.select_nth_unstable(123), 5);
+    }
 }
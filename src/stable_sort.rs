@@ -0,0 +1,489 @@
+use crate::ChunkedVec;
+use std::cmp::Ordering;
+use std::ptr;
+
+/// Implementation of stable sorting for ChunkedVec.
+///
+/// The algorithm is a bottom-up merge sort. Each chunk is first sorted on its
+/// own as a contiguous slice, then runs of chunks are merged pairwise with
+/// doubling widths. Merging uses a scratch buffer of at most one chunk (`N`
+/// elements): when the smaller of the two runs fits in the buffer it is merged
+/// directly, otherwise both runs are split around a binary-searched cut point
+/// and the middle is rotated in place before recursing. Adjacent runs that are
+/// already in order are not merged at all.
+///
+/// Elements moved into the scratch buffer are always written back, so if a
+/// caller-supplied comparator panics the vector is left in a valid (permuted)
+/// state with no leaks or double drops.
+impl<T, const N: usize> ChunkedVec<T, N> {
+    /// Sorts the vector, preserving the order of equal elements.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements). It
+    /// allocates a scratch buffer of at most `N` elements, on top of what
+    /// sorting a single chunk with [`slice::sort`] needs.
+    ///
+    /// # Complexity
+    /// Worst case *O*(*n* log² *n*); *O*(*n*) for already-sorted input.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let mut v = chunked_vec![-5, 4, 1, -3, 2];
+    ///
+    /// v.sort();
+    /// assert_eq!(v, [-5, -3, 1, 2, 4]);
+    /// ```
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(T::cmp);
+    }
+
+    /// Sorts the vector with a comparison function, preserving the order of
+    /// equal elements.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements). It
+    /// allocates a scratch buffer of at most `N` elements, on top of what
+    /// sorting a single chunk with [`slice::sort_by`] needs.
+    ///
+    /// If `compare` panics, the vector is left as a valid permutation of its
+    /// former contents.
+    ///
+    /// # Complexity
+    /// Worst case *O*(*n* log² *n*); *O*(*n*) for already-sorted input.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let mut v = chunked_vec![(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd')];
+    ///
+    /// v.sort_by(|a, b| a.0.cmp(&b.0));
+    /// assert_eq!(v, [(1, 'b'), (1, 'd'), (2, 'a'), (2, 'c')]);
+    /// ```
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let len = self.len;
+        if len < 2 {
+            return;
+        }
+
+        for chunk in self.chunks_mut() {
+            chunk.sort_by(&mut compare);
+        }
+        if len <= N {
+            return;
+        }
+
+        // Only used as raw storage: its length stays 0, so dropping it never
+        // drops elements that were moved back into the vector.
+        let cap = N.min(len / 2);
+        let mut scratch: Vec<T> = Vec::with_capacity(cap);
+        let buf = scratch.as_mut_ptr();
+
+        let mut width = N;
+        while width < len {
+            let mut lo = 0;
+            while lo + width < len {
+                let mid = lo + width;
+                let hi = (mid + width).min(len);
+                if self.cmp_at(mid - 1, mid, &mut compare) == Ordering::Greater {
+                    self.merge(lo, mid, hi, buf, cap, &mut compare);
+                }
+                lo = hi;
+            }
+            width *= 2;
+        }
+    }
+
+    /// Sorts the vector with a key extraction function, preserving the order
+    /// of equal elements.
+    ///
+    /// The key function is called *O*(*n* log *n*) times; for expensive keys
+    /// prefer [`sort_by_cached_key`].
+    ///
+    /// [`sort_by_cached_key`]: ChunkedVec::sort_by_cached_key
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let mut v = chunked_vec![-5i32, 4, 1, -3, 2, -1];
+    ///
+    /// v.sort_by_key(|k| k.abs());
+    /// assert_eq!(v, [1, -1, 2, -3, 4, -5]);
+    /// ```
+    pub fn sort_by_key<K, F>(&mut self, mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.sort_by(|a, b| f(a).cmp(&f(b)));
+    }
+
+    /// Sorts the vector with a key extraction function, preserving the order
+    /// of equal elements, calling the key function only once per element.
+    ///
+    /// The keys are computed up front and sorted together with the original
+    /// indices, after which the elements are permuted in place. This
+    /// allocates `len` key/index pairs, and is faster than [`sort_by_key`]
+    /// when the key function is expensive.
+    ///
+    /// [`sort_by_key`]: ChunkedVec::sort_by_key
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let mut v = chunked_vec![-5i32, 4, 32, -3, 2];
+    ///
+    /// v.sort_by_cached_key(|k| k.to_string());
+    /// assert_eq!(v, [-3, -5, 2, 32, 4]);
+    /// ```
+    pub fn sort_by_cached_key<K, F>(&mut self, f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        let len = self.len;
+        if len < 2 {
+            return;
+        }
+
+        // Pairing each key with its index makes the keys distinct, so an
+        // unstable sort of the pairs yields the stable order.
+        let mut indices: Vec<(K, usize)> = self.iter().map(f).zip(0..).collect();
+        indices.sort_unstable();
+
+        // `indices[i].1` is the original position of the element that belongs
+        // at `i`. Earlier positions have already been swapped away, so follow
+        // the chain of displacements to where that element is now.
+        for i in 0..len {
+            let mut index = indices[i].1;
+            while index < i {
+                index = indices[index].1;
+            }
+            indices[i].1 = index;
+            // Safety: both indices are below len.
+            unsafe { self.swap_unchecked(i, index) };
+        }
+    }
+
+    /// Stably merges the sorted runs `[lo, mid)` and `[mid, hi)`, using the
+    /// scratch buffer `buf` of `cap` elements.
+    fn merge<F>(
+        &mut self,
+        lo: usize,
+        mid: usize,
+        hi: usize,
+        buf: *mut T,
+        cap: usize,
+        compare: &mut F,
+    ) where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let (len1, len2) = (mid - lo, hi - mid);
+        if len1 == 0 || len2 == 0 {
+            return;
+        }
+        // Safety: the runs are within self.len and the smaller one fits in
+        // the buffer.
+        if len1 <= len2 && len1 <= cap {
+            unsafe { self.merge_lo(lo, mid, hi, buf, compare) };
+            return;
+        }
+        if len2 <= cap {
+            unsafe { self.merge_hi(lo, mid, hi, buf, compare) };
+            return;
+        }
+
+        // Split the longer run in half and find where its middle element
+        // lands in the other run, then swap the two inner pieces so that
+        // each half can be merged independently.
+        let (cut1, cut2) = if len1 > len2 {
+            let cut1 = lo + len1 / 2;
+            let (mut l, mut r) = (mid, hi);
+            while l < r {
+                let m = l + (r - l) / 2;
+                if self.cmp_at(m, cut1, compare) == Ordering::Less {
+                    l = m + 1;
+                } else {
+                    r = m;
+                }
+            }
+            (cut1, l)
+        } else {
+            let cut2 = mid + len2 / 2;
+            let (mut l, mut r) = (lo, mid);
+            while l < r {
+                let m = l + (r - l) / 2;
+                if self.cmp_at(cut2, m, compare) == Ordering::Less {
+                    r = m;
+                } else {
+                    l = m + 1;
+                }
+            }
+            (l, cut2)
+        };
+
+        self.rotate_range(cut1, mid, cut2);
+        let new_mid = cut1 + (cut2 - mid);
+        self.merge(lo, cut1, new_mid, buf, cap, compare);
+        self.merge(new_mid, cut2, hi, buf, cap, compare);
+    }
+
+    /// Merges by moving the left run into the buffer and filling the vector
+    /// from the front.
+    ///
+    /// # Safety
+    /// `lo < mid < hi <= self.len`, and `buf` must have room for `mid - lo`
+    /// elements.
+    unsafe fn merge_lo<F>(&mut self, lo: usize, mid: usize, hi: usize, buf: *mut T, compare: &mut F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.copy_range_out(lo, mid - lo, buf);
+        // The hole `[dest, dest + remaining)` always sits right before the
+        // next unmerged element of the right run.
+        let mut hole = MergeHole {
+            vec: self,
+            buf,
+            start: 0,
+            end: mid - lo,
+            dest: lo,
+        };
+        while hole.start < hole.end {
+            let right = hole.dest + (hole.end - hole.start);
+            if right == hi {
+                break;
+            }
+            let (src, dst) = hole.vec.get_elem_mut_ptr_pair(right, hole.dest);
+            let left = buf.add(hole.start);
+            // Take from the right only when strictly smaller, for stability.
+            if compare(&*src, &*left) == Ordering::Less {
+                ptr::copy_nonoverlapping(src, dst, 1);
+            } else {
+                ptr::copy_nonoverlapping(left, dst, 1);
+                hole.start += 1;
+            }
+            hole.dest += 1;
+        }
+        // Dropping the hole moves what is left of the buffer into place.
+    }
+
+    /// Merges by moving the right run into the buffer and filling the vector
+    /// from the back.
+    ///
+    /// # Safety
+    /// `lo < mid < hi <= self.len`, and `buf` must have room for `hi - mid`
+    /// elements.
+    unsafe fn merge_hi<F>(&mut self, lo: usize, mid: usize, hi: usize, buf: *mut T, compare: &mut F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.copy_range_out(mid, hi - mid, buf);
+        // The hole `[dest, dest + remaining)` always sits right after the
+        // next unmerged element of the left run.
+        let mut hole = MergeHole {
+            vec: self,
+            buf,
+            start: 0,
+            end: hi - mid,
+            dest: mid,
+        };
+        while hole.start < hole.end && hole.dest > lo {
+            let out = hole.dest + (hole.end - hole.start) - 1;
+            let (src, dst) = hole.vec.get_elem_mut_ptr_pair(hole.dest - 1, out);
+            let right = buf.add(hole.end - 1);
+            // Take from the left only when strictly greater, for stability.
+            if compare(&*right, &*src) == Ordering::Less {
+                ptr::copy_nonoverlapping(src, dst, 1);
+                hole.dest -= 1;
+            } else {
+                ptr::copy_nonoverlapping(right, dst, 1);
+                hole.end -= 1;
+            }
+        }
+        // Dropping the hole moves what is left of the buffer into place.
+    }
+}
+
+/// Tracks the elements still parked in the scratch buffer during a merge and
+/// the gap in the vector they belong to.
+///
+/// On drop (including unwinding from a panicking comparator) the remaining
+/// buffer elements are copied into the gap, so every slot is initialized
+/// exactly once again.
+struct MergeHole<'a, T, const N: usize> {
+    vec: &'a mut ChunkedVec<T, N>,
+    buf: *mut T,
+    start: usize,
+    end: usize,
+    /// Logical index of the first slot of the gap.
+    dest: usize,
+}
+
+impl<T, const N: usize> Drop for MergeHole<'_, T, N> {
+    fn drop(&mut self) {
+        // Safety: the gap is exactly `end - start` slots long and lies
+        // within the vector.
+        unsafe {
+            self.vec
+                .copy_range_in(self.buf.add(self.start), self.dest, self.end - self.start);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ChunkedVec, ChunkedVecSized};
+    use std::cell::Cell;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    /// Deterministic pseudo-random `(key, sequence)` pairs with many
+    /// duplicate keys.
+    fn keyed(len: usize, keys: u64) -> Vec<(u64, usize)> {
+        (0..len)
+            .map(|i| ((i as u64).wrapping_mul(2654435761) % keys, i))
+            .collect()
+    }
+
+    fn check_stable<const N: usize>(data: &[(u64, usize)]) {
+        let mut vec: ChunkedVec<(u64, usize), N> = ChunkedVecSized::new();
+        vec.extend(data.iter().copied());
+        let mut expected = data.to_vec();
+        expected.sort_by_key(|&(key, _)| key);
+
+        vec.sort_by_key(|&(key, _)| key);
+        assert_eq!(vec, expected);
+
+        let mut vec: ChunkedVec<(u64, usize), N> = ChunkedVecSized::new();
+        vec.extend(data.iter().copied());
+        vec.sort_by_cached_key(|&(key, _)| key);
+        assert_eq!(vec, expected);
+    }
+
+    #[test]
+    fn test_sort_is_stable() {
+        for len in [0, 1, 2, 5, 17, 64, 100, 257] {
+            for keys in [1, 3, 10, 1000] {
+                let data = keyed(len, keys);
+                check_stable::<1>(&data);
+                check_stable::<2>(&data);
+                check_stable::<3>(&data);
+                check_stable::<7>(&data);
+                check_stable::<64>(&data);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sort_patterns() {
+        let patterns: [Vec<i32>; 4] = [
+            (0..100).collect(),
+            (0..100).rev().collect(),
+            (0..100).map(|i| i % 7).collect(),
+            (0..100)
+                .map(|i| if i % 2 == 0 { i } else { 100 - i })
+                .collect(),
+        ];
+        for data in patterns {
+            let mut expected = data.clone();
+            expected.sort();
+
+            let mut vec: ChunkedVec<i32, 4> = ChunkedVecSized::new();
+            vec.extend(data.iter().copied());
+            vec.sort();
+            assert_eq!(vec, expected);
+        }
+    }
+
+    #[test]
+    fn test_sort_by_descending_is_stable() {
+        let mut vec: ChunkedVec<(i32, char), 2> = ChunkedVecSized::new();
+        vec.extend([(1, 'a'), (2, 'b'), (1, 'c'), (3, 'd'), (2, 'e'), (1, 'f')]);
+
+        vec.sort_by(|a, b| b.0.cmp(&a.0));
+
+        assert_eq!(
+            vec,
+            [(3, 'd'), (2, 'b'), (2, 'e'), (1, 'a'), (1, 'c'), (1, 'f')]
+        );
+    }
+
+    #[test]
+    fn test_sort_large() {
+        let data: Vec<(u64, usize)> = keyed(10_000, 500);
+        let mut vec = ChunkedVec::<(u64, usize)>::new();
+        vec.extend(data.iter().copied());
+
+        vec.sort_by_key(|&(key, _)| key);
+
+        let mut expected = data;
+        expected.sort_by_key(|&(key, _)| key);
+        assert_eq!(vec, expected);
+    }
+
+    #[test]
+    fn test_sort_by_cached_key_calls_key_once_per_element() {
+        let calls = Cell::new(0);
+        let mut vec: ChunkedVec<i32, 3> = ChunkedVecSized::new();
+        vec.extend([5, -3, 8, -1, 0, 2, -7, 4, 6, -2]);
+
+        vec.sort_by_cached_key(|&x| {
+            calls.set(calls.get() + 1);
+            x.abs()
+        });
+
+        assert_eq!(calls.get(), 10);
+        assert_eq!(vec, [0, -1, 2, -2, -3, 4, 5, 6, -7, 8]);
+    }
+
+    #[test]
+    fn test_panicking_comparator_leaves_valid_state() {
+        struct Counted<'a> {
+            value: i32,
+            drops: &'a Cell<usize>,
+        }
+
+        impl Drop for Counted<'_> {
+            fn drop(&mut self) {
+                self.drops.set(self.drops.get() + 1);
+            }
+        }
+
+        // Panic at several points so that both the chunk sorts and the
+        // buffered and rotating merges are interrupted.
+        for panic_at in [1, 10, 40, 80, 120, 200] {
+            let drops = Cell::new(0);
+            let len = 50;
+            let mut vec: ChunkedVec<Counted<'_>, 4> = ChunkedVecSized::new();
+            for i in 0..len {
+                vec.push(Counted {
+                    value: (i * 37 % len) as i32,
+                    drops: &drops,
+                });
+            }
+
+            let mut calls = 0;
+            let result = catch_unwind(AssertUnwindSafe(|| {
+                vec.sort_by(|a, b| {
+                    calls += 1;
+                    if calls == panic_at {
+                        panic!("comparator panic");
+                    }
+                    a.value.cmp(&b.value)
+                })
+            }));
+            assert!(result.is_err());
+
+            assert_eq!(drops.get(), 0);
+            let mut values: Vec<i32> = vec.iter().map(|c| c.value).collect();
+            values.sort();
+            assert_eq!(values, (0..len as i32).collect::<Vec<_>>());
+
+            drop(vec);
+            assert_eq!(drops.get(), len);
+        }
+    }
+}
@@ -1,41 +1,249 @@
-use crate::ChunkedVec;
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+use crate::{ChunkedVec, ChunkedVecSized};
 
 /// Implementation of the Default trait for ChunkedVec.
 ///
 /// This implementation provides a way to create an empty ChunkedVec using the default() method.
-/// The created vector will use the default chunk size (64) and have no pre-allocated chunks.
+/// The created vector uses the chunk size `N` of the annotated type (64 unless specified) and
+/// has no pre-allocated chunks.
 ///
 /// # Examples
 /// ```
 /// use chunked_vec::ChunkedVec;
 /// let vec: ChunkedVec<i32> = ChunkedVec::default();
 /// assert!(vec.is_empty());
+///
+/// let sized: ChunkedVec<i32, 8> = ChunkedVec::default();
+/// assert_eq!(sized.capacity(), 0);
 /// ```
-impl<T> Default for ChunkedVec<T> {
+impl<T, const N: usize> Default for ChunkedVec<T, N> {
     fn default() -> Self {
-        Self::new()
+        ChunkedVecSized::new()
+    }
+}
+
+/// Implementation of the Clone trait for ChunkedVec.
+///
+/// The clone allocates only the chunks needed to hold the elements and fills them
+/// chunk by chunk. If `T::clone` panics part-way, the elements cloned so far are
+/// dropped and no partially initialized chunk is ever exposed.
+///
+/// # Examples
+/// ```
+/// use chunked_vec::chunked_vec;
+/// let vec = chunked_vec![String::from("a"), String::from("b")];
+/// let copy = vec.clone();
+/// assert_eq!(vec, copy);
+/// ```
+impl<T: Clone, const N: usize> Clone for ChunkedVec<T, N> {
+    fn clone(&self) -> Self {
+        let mut new = ChunkedVecSized::<T, N>::with_chunk_count(self.len.div_ceil(N));
+        for src in self.chunks() {
+            new.data.push(Self::create_empty_chunk());
+            let dst = new.data.last_mut().unwrap();
+            for (slot, value) in dst.iter_mut().zip(src) {
+                slot.write(value.clone());
+                // Counting each element as soon as it is written lets `Drop`
+                // clean up if a later clone panics.
+                new.len += 1;
+            }
+        }
+        new
+    }
+
+    /// Overwrites `self` with a clone of `source`, reusing the existing
+    /// elements via [`Clone::clone_from`] and the already allocated chunks.
+    fn clone_from(&mut self, source: &Self) {
+        self.truncate(source.len);
+        let prefix = self.len;
+        for (dst, src) in self.iter_mut().zip(source.iter()) {
+            dst.clone_from(src);
+        }
+        self.extend(source.iter().skip(prefix).cloned());
+    }
+}
+
+/// Implementation of the Hash trait for ChunkedVec.
+///
+/// The length is written first and each chunk is then hashed as a slice, so
+/// a `ChunkedVec` makes one batch of writes per chunk where a `[T]` makes one
+/// for all its elements. Equal vectors always hash equally.
+///
+/// For hashers that treat consecutive writes as one byte stream, such as the
+/// standard library's `DefaultHasher` (used by `RandomState`), the result is
+/// also the same as for the `Vec<T>` or `[T]` with the same contents. Hashers
+/// that process each write as its own block, such as FxHash or aHash, give a
+/// different hash than the `[T]` does.
+///
+/// # Examples
+/// ```
+/// use std::hash::{BuildHasher, RandomState};
+/// use chunked_vec::ChunkedVec;
+///
+/// let state = RandomState::new();
+/// let vec: ChunkedVec<u8, 4> = ChunkedVec::from(b"hello world");
+/// assert_eq!(state.hash_one(&vec), state.hash_one(b"hello world".to_vec()));
+/// ```
+impl<T: Hash, const N: usize> Hash for ChunkedVec<T, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len);
+        for chunk in self.chunks() {
+            T::hash_slice(chunk, state);
+        }
     }
 }
 
-// TODO: Temporary implementation to cope with doctest
-// src/operations.rs:169
-impl<T, const N: usize, const M: usize> PartialEq<[T; M]> for ChunkedVec<T, N>
+/// Implementation of the PartialEq trait between ChunkedVecs.
+///
+/// Two vectors are equal when they have the same length and pairwise equal
+/// elements; the chunk sizes do not need to match.
+///
+/// # Examples
+/// ```
+/// use chunked_vec::ChunkedVec;
+/// let a: ChunkedVec<i32, 2> = ChunkedVec::from([1, 2, 3]);
+/// let b: ChunkedVec<i32, 64> = ChunkedVec::from([1, 2, 3]);
+/// assert_eq!(a, b);
+/// ```
+impl<T, U, const N: usize, const M: usize> PartialEq<ChunkedVec<U, M>> for ChunkedVec<T, N>
 where
-    T: PartialEq,
+    T: PartialEq<U>,
 {
-    fn eq(&self, other: &[T; M]) -> bool {
-        self.iter().eq(other.iter())
+    fn eq(&self, other: &ChunkedVec<U, M>) -> bool {
+        if self.len != other.len {
+            return false;
+        }
+        if N == M {
+            // Same layout: compare whole chunks as slices.
+            self.chunks().zip(other.chunks()).all(|(a, b)| a == b)
+        } else {
+            self.iter().zip(other.iter()).all(|(a, b)| a == b)
+        }
     }
 }
 
+impl<T: Eq, const N: usize> Eq for ChunkedVec<T, N> {}
+
+/// Implementation of the PartialOrd trait for ChunkedVec.
+///
+/// Vectors are compared lexicographically, like `Vec<T>` and `[T]`.
+impl<T: PartialOrd, const N: usize> PartialOrd for ChunkedVec<T, N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+/// Implementation of the Ord trait for ChunkedVec.
+///
+/// Vectors are ordered lexicographically, like `Vec<T>` and `[T]`.
+impl<T: Ord, const N: usize> Ord for ChunkedVec<T, N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+fn chunked_eq_slice<T, U, const N: usize>(vec: &ChunkedVec<T, N>, other: &[U]) -> bool
+where
+    T: PartialEq<U>,
+{
+    vec.len == other.len() && vec.chunks().zip(other.chunks(N)).all(|(a, b)| a == b)
+}
+
+fn slice_eq_chunked<T, U, const N: usize>(slice: &[U], vec: &ChunkedVec<T, N>) -> bool
+where
+    U: PartialEq<T>,
+{
+    slice.len() == vec.len && slice.chunks(N).zip(vec.chunks()).all(|(a, b)| a == b)
+}
+
+/// Implements `PartialEq` in both directions between `ChunkedVec<T, N>` and a
+/// contiguous sequence of `U`, comparing chunk by chunk.
+macro_rules! impl_slice_eq {
+    ([$($vars:tt)*] $rhs:ty) => {
+        impl<T, U, const N: usize, $($vars)*> PartialEq<$rhs> for ChunkedVec<T, N>
+        where
+            T: PartialEq<U>,
+        {
+            #[inline]
+            fn eq(&self, other: &$rhs) -> bool {
+                chunked_eq_slice(self, &other[..])
+            }
+        }
+
+        impl<T, U, const N: usize, $($vars)*> PartialEq<ChunkedVec<T, N>> for $rhs
+        where
+            U: PartialEq<T>,
+        {
+            #[inline]
+            fn eq(&self, other: &ChunkedVec<T, N>) -> bool {
+                slice_eq_chunked(&self[..], other)
+            }
+        }
+    };
+}
+
+impl_slice_eq! { [] Vec<U> }
+impl_slice_eq! { [] [U] }
+impl_slice_eq! { [] &[U] }
+impl_slice_eq! { [] &mut [U] }
+impl_slice_eq! { [const M: usize] [U; M] }
+impl_slice_eq! { [const M: usize] &[U; M] }
+
+/// Extends the vector with the contents of an iterator.
+///
+/// Chunks are allocated upfront for the iterator's lower size bound. The
+/// free slots of each chunk are then written in one pass, so there is no
+/// per-element division or chunk lookup.
 impl<T, const N: usize> Extend<T> for ChunkedVec<T, N> {
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
-        for item in iter {
-            self.push(item);
+        let mut iter = iter.into_iter();
+        self.allocate_chunks_for(iter.size_hint().0);
+
+        loop {
+            if self.len == self.allocated_capacity() {
+                match iter.next() {
+                    Some(item) => self.push(item),
+                    None => return,
+                }
+                self.allocate_chunks_for(iter.size_hint().0);
+                continue;
+            }
+
+            let (chunk_idx, offset) = self.chunk_and_offset(self.len);
+            let slots = &mut self.data[chunk_idx][offset..];
+            let free = slots.len();
+            let start = self.len;
+            // `zip` stops at the last free slot without pulling another
+            // item, and `len` counts every write in case `next` panics.
+            for (slot, item) in slots.iter_mut().zip(&mut iter) {
+                slot.write(item);
+                self.len += 1;
+            }
+            if self.len - start < free {
+                return;
+            }
         }
     }
 }
 
+/// Extends the vector with copies of the referenced elements.
+///
+/// # Examples
+/// ```
+/// use chunked_vec::{ChunkedVec, ChunkedVecSized};
+/// let mut vec: ChunkedVec<i32, 2> = ChunkedVecSized::new();
+/// vec.extend(&[1, 2, 3]);
+/// vec.extend([4, 5].iter());
+/// assert_eq!(vec, [1, 2, 3, 4, 5]);
+/// ```
+impl<'a, T: Copy + 'a, const N: usize> Extend<&'a T> for ChunkedVec<T, N> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -44,4 +252,280 @@ mod tests {
         let vec = ChunkedVec::<()>::default();
         assert_eq!(vec.len(), 0);
     }
+
+    #[test]
+    fn test_default_generic_chunk_size() {
+        let vec: ChunkedVec<i32, 4> = Default::default();
+        assert_eq!(vec.len(), 0);
+        assert_eq!(vec.capacity(), 0);
+    }
+
+    #[test]
+    fn test_clone_across_chunks() {
+        let vec: ChunkedVec<String, 3> = (0..10).map(|i| i.to_string()).collect();
+        let copy = vec.clone();
+        assert_eq!(copy, vec);
+        assert_eq!(copy.len(), 10);
+        assert_eq!(copy.allocated_capacity(), 12);
+
+        let empty: ChunkedVec<String, 3> = ChunkedVecSized::new();
+        assert_eq!(empty.clone().allocated_capacity(), 0);
+    }
+
+    #[test]
+    fn test_clone_panic_drops_cloned_prefix() {
+        use std::cell::Cell;
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        struct Bomb<'a> {
+            id: usize,
+            drops: &'a Cell<usize>,
+        }
+
+        impl Clone for Bomb<'_> {
+            fn clone(&self) -> Self {
+                if self.id == 5 {
+                    panic!("clone panic");
+                }
+                Bomb {
+                    id: self.id,
+                    drops: self.drops,
+                }
+            }
+        }
+
+        impl Drop for Bomb<'_> {
+            fn drop(&mut self) {
+                self.drops.set(self.drops.get() + 1);
+            }
+        }
+
+        let drops = Cell::new(0);
+        let mut vec: ChunkedVec<Bomb<'_>, 2> = ChunkedVecSized::new();
+        for id in 0..8 {
+            vec.push(Bomb { id, drops: &drops });
+        }
+
+        let result = catch_unwind(AssertUnwindSafe(|| vec.clone()));
+        assert!(result.is_err());
+        // The five clones made before the panic are dropped exactly once
+        assert_eq!(drops.get(), 5);
+
+        drop(vec);
+        assert_eq!(drops.get(), 13);
+    }
+
+    #[test]
+    fn test_clone_from_reuses_chunks() {
+        let source: ChunkedVec<i32, 4> = (0..6).collect();
+
+        let mut longer: ChunkedVec<i32, 4> = (10..22).collect();
+        longer.clone_from(&source);
+        assert_eq!(longer, source);
+        assert_eq!(longer.allocated_capacity(), 12);
+
+        let mut shorter: ChunkedVec<i32, 4> = (10..12).collect();
+        shorter.clone_from(&source);
+        assert_eq!(shorter, source);
+    }
+
+    #[test]
+    fn test_eq_cross_types() {
+        let vec: ChunkedVec<i32, 3> = (1..=7).collect();
+        let expected: Vec<i32> = (1..=7).collect();
+
+        assert_eq!(vec, expected);
+        assert_eq!(expected, vec);
+        assert_eq!(vec, expected[..]);
+        assert_eq!(expected[..], vec);
+        assert_eq!(vec, &expected[..]);
+        assert_eq!(&expected[..], vec);
+        assert_eq!(vec, [1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!([1, 2, 3, 4, 5, 6, 7], vec);
+        assert_eq!(vec, &[1, 2, 3, 4, 5, 6, 7]);
+
+        assert_ne!(vec, expected[..6]);
+        assert_ne!(vec, [1, 2, 3, 4, 5, 6, 8]);
+
+        // Different chunk sizes compare element-wise
+        let other: ChunkedVec<i32, 5> = (1..=7).collect();
+        assert_eq!(vec, other);
+        let shorter: ChunkedVec<i32, 5> = (1..=6).collect();
+        assert_ne!(vec, shorter);
+
+        // Heterogeneous element types
+        let strings: ChunkedVec<String, 2> =
+            ["a", "b", "c"].iter().map(|s| s.to_string()).collect();
+        assert_eq!(strings, ["a", "b", "c"]);
+        assert_eq!(strings, vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_ord_is_lexicographic() {
+        let a: ChunkedVec<i32, 2> = ChunkedVec::from([1, 2, 3]);
+        let b: ChunkedVec<i32, 2> = ChunkedVec::from([1, 2, 4]);
+        let c: ChunkedVec<i32, 2> = ChunkedVec::from([1, 2]);
+        assert!(a < b);
+        assert!(c < a);
+        assert_eq!(a.cmp(&a.clone()), std::cmp::Ordering::Equal);
+
+        let mut vecs = vec![b.clone(), a.clone(), c.clone()];
+        vecs.sort();
+        assert_eq!(vecs, [c, a, b]);
+
+        let nan: ChunkedVec<f64, 2> = ChunkedVec::from([1.0, f64::NAN]);
+        assert_eq!(nan.partial_cmp(&nan), None);
+    }
+
+    #[test]
+    fn test_hash_matches_slice() {
+        use std::collections::HashMap;
+        use std::hash::{BuildHasher, RandomState};
+
+        let state = RandomState::new();
+        for len in 0..20 {
+            let bytes: Vec<u8> = (0..len).collect();
+            let strings: Vec<String> = (0..len).map(|i| i.to_string()).collect();
+
+            let chunked: ChunkedVec<u8, 3> = ChunkedVec::from(&bytes[..]);
+            assert_eq!(state.hash_one(&chunked), state.hash_one(&bytes[..]));
+            let chunked: ChunkedVec<String, 4> = ChunkedVec::from(&strings[..]);
+            assert_eq!(state.hash_one(&chunked), state.hash_one(&strings));
+        }
+
+        let mut map = HashMap::new();
+        map.insert(ChunkedVec::<i32, 2>::from([1, 2, 3]), "one");
+        map.insert(ChunkedVec::<i32, 2>::from([4, 5]), "two");
+        assert_eq!(
+            map.get(&ChunkedVec::<i32, 2>::from([1, 2, 3])),
+            Some(&"one")
+        );
+        assert_eq!(map.get(&ChunkedVec::<i32, 2>::from([4])), None);
+    }
+
+    #[test]
+    fn test_hash_writes_one_block_per_chunk() {
+        /// Keeps every write separate, like block-based hashers do.
+        #[derive(Default)]
+        struct WriteLog(Vec<Vec<u8>>);
+
+        impl Hasher for WriteLog {
+            fn write(&mut self, bytes: &[u8]) {
+                self.0.push(bytes.to_vec());
+            }
+
+            fn finish(&self) -> u64 {
+                unimplemented!()
+            }
+        }
+
+        fn writes<H: Hash + ?Sized>(value: &H) -> Vec<Vec<u8>> {
+            let mut log = WriteLog::default();
+            value.hash(&mut log);
+            log.0
+        }
+
+        let bytes =
+            |values: &[u32]| -> Vec<u8> { values.iter().flat_map(|v| v.to_ne_bytes()).collect() };
+        let len = 3usize.to_ne_bytes().to_vec();
+
+        let vec: ChunkedVec<u32, 2> = ChunkedVec::from([1, 2, 3]);
+        assert_eq!(writes(&vec), [len.clone(), bytes(&[1, 2]), bytes(&[3])]);
+        // Equal vectors hash equally, but the slice writes all its elements
+        // at once
+        assert_eq!(writes(&vec), writes(&vec.clone()));
+        assert_eq!(writes(&[1u32, 2, 3][..]), [len, bytes(&[1, 2, 3])]);
+    }
+
+    /// Yields `items` while reporting a fixed size hint, which may be wrong.
+    struct Hinted<I> {
+        items: I,
+        lower: usize,
+    }
+
+    impl<I: Iterator> Iterator for Hinted<I> {
+        type Item = I::Item;
+
+        fn next(&mut self) -> Option<I::Item> {
+            self.items.next()
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.lower, None)
+        }
+    }
+
+    #[test]
+    fn test_extend_matches_vec() {
+        for start in 0..9 {
+            for count in 0..20 {
+                let mut vec: ChunkedVec<String, 4> = (0..start).map(|i| i.to_string()).collect();
+                // Exact hint, no hint and an unrelated hint all give the same result
+                vec.extend((start..start + count).map(|i| i.to_string()));
+                vec.extend((0..count).filter(|_| true).map(|i| i.to_string()));
+                vec.extend(Hinted {
+                    items: (0..count).map(|i| i.to_string()),
+                    lower: 7,
+                });
+
+                let mut expected: Vec<String> = (0..start + count).map(|i| i.to_string()).collect();
+                expected.extend((0..count).map(|i| i.to_string()));
+                expected.extend((0..count).map(|i| i.to_string()));
+                assert_eq!(vec, expected, "start = {start}, count = {count}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_extend_reserves_from_size_hint() {
+        let mut vec: ChunkedVec<u32, 4> = ChunkedVecSized::new();
+        vec.extend(0..9);
+        assert_eq!(vec.allocated_capacity(), 12);
+
+        // Retained chunks are filled before new ones are allocated
+        vec.truncate(2);
+        vec.extend(2..12);
+        assert_eq!(vec.allocated_capacity(), 12);
+        assert!(vec.iter().copied().eq(0..12));
+
+        // A chained iterator's hint grows once the first part is exhausted
+        let mut vec: ChunkedVec<u32, 4> = ChunkedVecSized::new();
+        vec.extend((0..3).filter(|_| true).chain(3..10));
+        assert!(vec.iter().copied().eq(0..10));
+        assert_eq!(vec.allocated_capacity(), 12);
+    }
+
+    #[test]
+    fn test_extend_panicking_iterator() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        use std::rc::Rc;
+
+        let value = Rc::new(());
+        let mut vec: ChunkedVec<Rc<()>, 3> = ChunkedVecSized::new();
+        vec.push(value.clone());
+
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            vec.extend((0..10).map(|i| {
+                if i == 6 {
+                    panic!("iterator failed");
+                }
+                value.clone()
+            }))
+        }));
+        assert!(result.is_err());
+        assert_eq!(vec.len(), 7);
+        assert_eq!(Rc::strong_count(&value), 8);
+
+        drop(vec);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn test_extend_by_reference() {
+        let data = [1u8, 2, 3, 4, 5, 6, 7];
+        let mut vec: ChunkedVec<u8, 3> = ChunkedVecSized::new();
+        vec.extend(&data);
+        vec.extend(data[..2].iter());
+        assert_eq!(vec, [1, 2, 3, 4, 5, 6, 7, 1, 2]);
+    }
 }
@@ -0,0 +1,92 @@
+//! The `Send`, `Sync`, `UnwindSafe` and `RefUnwindSafe` bounds of the public
+//! types. Negative cases that need a compile error to show are in
+//! `tests/compile-fail`.
+
+use std::cell::Cell;
+use std::panic::{RefUnwindSafe, UnwindSafe};
+use std::rc::Rc;
+use std::sync::MutexGuard;
+
+use chunked_vec::{
+    ChunkedSlice, ChunkedSliceMut, ChunkedVec, Chunks, ChunksMut, ConcurrentChunkedVec, Drain,
+    IntoIter, Iter, IterMut, PersistentChunkedVec, PersistentIter,
+};
+use static_assertions::{assert_impl_all, assert_not_impl_any};
+
+/// `Send` but not `Sync`.
+type SendOnly = Cell<i32>;
+/// `Sync` but not `Send`.
+type SyncOnly = MutexGuard<'static, i32>;
+/// Neither `Send` nor `Sync`.
+type Neither = Rc<i32>;
+
+// Owning types: `Send` if `T: Send`, `Sync` if `T: Sync`
+assert_impl_all!(ChunkedVec<i32>: Send, Sync, UnwindSafe, RefUnwindSafe, Unpin);
+assert_impl_all!(ChunkedVec<SendOnly, 4>: Send);
+assert_not_impl_any!(ChunkedVec<SendOnly, 4>: Sync);
+assert_impl_all!(ChunkedVec<SyncOnly, 4>: Sync);
+assert_not_impl_any!(ChunkedVec<SyncOnly, 4>: Send);
+assert_not_impl_any!(ChunkedVec<Neither, 4>: Send, Sync);
+
+assert_impl_all!(IntoIter<i32, 4>: Send, Sync);
+assert_impl_all!(IntoIter<SendOnly, 4>: Send);
+assert_not_impl_any!(IntoIter<SendOnly, 4>: Sync);
+assert_impl_all!(IntoIter<SyncOnly, 4>: Sync);
+assert_not_impl_any!(IntoIter<SyncOnly, 4>: Send);
+assert_not_impl_any!(IntoIter<Neither, 4>: Send, Sync);
+
+// Shared borrows: `Send` and `Sync` if `T: Sync`
+assert_impl_all!(Iter<'static, i32, 4>: Send, Sync);
+assert_not_impl_any!(Iter<'static, SendOnly, 4>: Send, Sync);
+assert_impl_all!(Iter<'static, SyncOnly, 4>: Send, Sync);
+assert_impl_all!(Chunks<'static, SyncOnly, 4>: Send, Sync);
+assert_not_impl_any!(Chunks<'static, SendOnly, 4>: Send, Sync);
+assert_impl_all!(ChunkedSlice<'static, SyncOnly, 4>: Send, Sync);
+assert_not_impl_any!(ChunkedSlice<'static, SendOnly, 4>: Send, Sync);
+
+// Mutable borrows: `Send` if `T: Send`, `Sync` if `T: Sync`
+assert_impl_all!(IterMut<'static, i32, 4>: Send, Sync);
+assert_impl_all!(IterMut<'static, SendOnly, 4>: Send);
+assert_not_impl_any!(IterMut<'static, SendOnly, 4>: Sync);
+assert_impl_all!(IterMut<'static, SyncOnly, 4>: Sync);
+assert_not_impl_any!(IterMut<'static, SyncOnly, 4>: Send);
+assert_not_impl_any!(IterMut<'static, Neither, 4>: Send, Sync);
+assert_impl_all!(ChunksMut<'static, SendOnly, 4>: Send);
+assert_not_impl_any!(ChunksMut<'static, SendOnly, 4>: Sync);
+assert_impl_all!(Drain<'static, SendOnly, 4>: Send);
+assert_not_impl_any!(Drain<'static, SendOnly, 4>: Sync);
+assert_impl_all!(ChunkedSliceMut<'static, SendOnly, 4>: Send);
+assert_not_impl_any!(ChunkedSliceMut<'static, SendOnly, 4>: Sync);
+
+// Shared between writers: `Sync` needs `T: Send + Sync`
+assert_impl_all!(ConcurrentChunkedVec<i32, 4>: Send, Sync);
+assert_impl_all!(ConcurrentChunkedVec<SendOnly, 4>: Send);
+assert_not_impl_any!(ConcurrentChunkedVec<SendOnly, 4>: Sync);
+assert_not_impl_any!(ConcurrentChunkedVec<SyncOnly, 4>: Send, Sync);
+
+// Shared chunks are reference counted across threads: both need
+// `T: Send + Sync`
+assert_impl_all!(PersistentChunkedVec<i32, 4>: Send, Sync);
+assert_not_impl_any!(PersistentChunkedVec<SendOnly, 4>: Send, Sync);
+assert_not_impl_any!(PersistentChunkedVec<SyncOnly, 4>: Send, Sync);
+assert_impl_all!(PersistentIter<'static, i32, 4>: Send, Sync);
+assert_not_impl_any!(PersistentIter<'static, SendOnly, 4>: Send, Sync);
+
+// Unwind safety follows the element type
+assert_impl_all!(ChunkedVec<String, 4>: UnwindSafe, RefUnwindSafe);
+assert_not_impl_any!(ChunkedVec<SendOnly, 4>: RefUnwindSafe);
+assert_not_impl_any!(ChunkedVec<&'static mut i32, 4>: UnwindSafe);
+
+#[cfg(feature = "rayon")]
+mod rayon {
+    use super::{Neither, SendOnly, SyncOnly};
+    use chunked_vec::{IntoParIter, ParIter, ParIterMut};
+    use static_assertions::{assert_impl_all, assert_not_impl_any};
+
+    assert_impl_all!(ParIter<'static, SyncOnly, 4>: Send, Sync);
+    assert_not_impl_any!(ParIter<'static, SendOnly, 4>: Send, Sync);
+    assert_impl_all!(ParIterMut<'static, SendOnly, 4>: Send);
+    assert_not_impl_any!(ParIterMut<'static, SendOnly, 4>: Sync);
+    assert_impl_all!(IntoParIter<SendOnly, 4>: Send);
+    assert_not_impl_any!(IntoParIter<Neither, 4>: Send, Sync);
+}
@@ -0,0 +1,11 @@
+use std::cell::Cell;
+use std::thread;
+
+use chunked_vec::ConcurrentChunkedVec;
+
+fn main() {
+    let vec: ConcurrentChunkedVec<Cell<i32>> = ConcurrentChunkedVec::new();
+    thread::scope(|s| {
+        s.spawn(|| vec.push(Cell::new(1)));
+    });
+}
@@ -0,0 +1,11 @@
+use std::rc::Rc;
+use std::thread;
+
+use chunked_vec::ChunkedVec;
+
+fn main() {
+    let mut vec: ChunkedVec<Rc<i32>> = ChunkedVec::new();
+    vec.push(Rc::new(1));
+    let iter = vec.into_iter();
+    thread::spawn(move || iter.count());
+}
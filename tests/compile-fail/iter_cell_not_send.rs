@@ -0,0 +1,14 @@
+use std::cell::Cell;
+use std::thread;
+
+use chunked_vec::ChunkedVec;
+
+fn main() {
+    let mut vec: ChunkedVec<Cell<i32>> = ChunkedVec::new();
+    vec.push(Cell::new(1));
+    // Sending `Iter` would share the `Cell`s between threads
+    let iter = vec.iter();
+    thread::scope(|s| {
+        s.spawn(move || iter.count());
+    });
+}
@@ -0,0 +1,13 @@
+use std::rc::Rc;
+use std::thread;
+
+use chunked_vec::ChunkedVec;
+
+fn main() {
+    let mut vec: ChunkedVec<Rc<i32>> = ChunkedVec::new();
+    vec.push(Rc::new(1));
+    let iter = vec.iter_mut();
+    thread::scope(|s| {
+        s.spawn(move || iter.count());
+    });
+}
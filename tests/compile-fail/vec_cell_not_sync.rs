@@ -0,0 +1,13 @@
+use std::cell::Cell;
+use std::thread;
+
+use chunked_vec::ChunkedVec;
+
+fn main() {
+    let mut vec: ChunkedVec<Cell<i32>> = ChunkedVec::new();
+    vec.push(Cell::new(1));
+    let vec = &vec;
+    thread::scope(|s| {
+        s.spawn(move || vec[0].set(2));
+    });
+}
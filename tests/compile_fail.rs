@@ -0,0 +1,10 @@
+//! Compile-fail tests for the auto trait bounds of the public types.
+//!
+//! After a compiler upgrade changes the diagnostics, regenerate the expected
+//! output with `TRYBUILD=overwrite cargo test --test compile_fail`.
+
+#[test]
+fn compile_fail() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/compile-fail/*.rs");
+}
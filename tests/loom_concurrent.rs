@@ -0,0 +1,107 @@
+//! Loom models for `ConcurrentChunkedVec`.
+//!
+//! Run with:
+//!
+//! ```text
+//! RUSTFLAGS="--cfg loom" cargo test --release --test loom_concurrent
+//! ```
+#![cfg(loom)]
+
+use chunked_vec::{ChunkedVec, ConcurrentChunkedVec};
+use loom::sync::Arc;
+use loom::thread;
+
+/// Two threads push into the same, not yet allocated, chunk. Both race to
+/// allocate it, and exactly one allocation must win.
+#[test]
+fn concurrent_push_claims_unique_slots() {
+    loom::model(|| {
+        let vec = Arc::new(ConcurrentChunkedVec::<usize, 2>::new());
+        let handles: Vec<_> = (0..2)
+            .map(|t| {
+                let vec = vec.clone();
+                thread::spawn(move || vec.push(t))
+            })
+            .collect();
+
+        let mut indices: Vec<usize> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+        indices.sort_unstable();
+        assert_eq!(indices, [0, 1]);
+        assert_eq!(vec.len(), 2);
+    });
+}
+
+/// Pushes that land in different chunks and segments race to allocate the
+/// segments.
+#[test]
+fn concurrent_push_across_chunks() {
+    loom::model(|| {
+        let vec = Arc::new(ConcurrentChunkedVec::<usize, 1>::new());
+        let other = {
+            let vec = vec.clone();
+            thread::spawn(move || {
+                let a = vec.push(10);
+                let b = vec.push(11);
+                assert!(a < b);
+                assert_eq!(vec.get(b), Some(&11));
+            })
+        };
+        let c = vec.push(20);
+        assert_eq!(vec.get(c), Some(&20));
+        other.join().unwrap();
+
+        let vec = Arc::try_unwrap(vec).unwrap();
+        let mut vec: ChunkedVec<usize, 1> = vec.into_chunked_vec();
+        vec.sort_unstable();
+        assert_eq!(vec, [10, 11, 20]);
+    });
+}
+
+/// A reader polling an index sees nothing until the element is fully
+/// written.
+#[test]
+fn get_sees_published_value() {
+    loom::model(|| {
+        let vec = Arc::new(ConcurrentChunkedVec::<String, 2>::new());
+        let writer = {
+            let vec = vec.clone();
+            thread::spawn(move || {
+                vec.push("first".to_string());
+            })
+        };
+
+        loop {
+            if let Some(value) = vec.get(0) {
+                assert_eq!(value, "first");
+                break;
+            }
+            thread::yield_now();
+        }
+        writer.join().unwrap();
+    });
+}
+
+/// Elements pushed by other threads are dropped exactly once, whether the
+/// vector is dropped or converted.
+#[test]
+fn drop_and_convert_after_join() {
+    loom::model(|| {
+        let value = Arc::new(());
+        let vec = Arc::new(ConcurrentChunkedVec::<Arc<()>, 2>::new());
+        let writer = {
+            let (vec, value) = (vec.clone(), value.clone());
+            thread::spawn(move || {
+                vec.push(value.clone());
+                vec.push(value);
+            })
+        };
+        vec.push(value.clone());
+        writer.join().unwrap();
+
+        let vec = Arc::try_unwrap(vec).unwrap().into_chunked_vec();
+        assert_eq!(vec.len(), 3);
+        assert_eq!(Arc::strong_count(&value), 4);
+        drop(vec);
+        assert_eq!(Arc::strong_count(&value), 1);
+    });
+}
@@ -2,7 +2,7 @@
 
 use std::sync::atomic::{AtomicUsize, Ordering::SeqCst};
 
-use chunked_vec::{chunked_vec, ChunkedVec, ChunkedVecSized};
+use chunked_vec::{chunked_vec, ChunkedVec, ChunkedVecSized, PersistentChunkedVec};
 use serde::de::{Deserialize, Deserializer};
 
 fn round_trip<const N: usize>(vec: &ChunkedVec<i32, N>) -> ChunkedVec<i32, N> {
@@ -87,6 +87,22 @@ fn chunk_size_is_not_part_of_format() {
     assert!(back.iter().eq(vec.iter()));
 }
 
+#[test]
+fn persistent_interop_with_chunked_vec() {
+    let vec: PersistentChunkedVec<i32, 4> = (0..10).collect();
+    let snapshot = vec.clone();
+    let json = serde_json::to_string(&vec).unwrap();
+    assert_eq!(
+        json,
+        serde_json::to_string(&(0..10).collect::<Vec<_>>()).unwrap()
+    );
+
+    let back: PersistentChunkedVec<i32, 3> = serde_json::from_str(&json).unwrap();
+    assert!(back.iter().eq(snapshot.iter()));
+    let chunked: ChunkedVec<i32, 4> = serde_json::from_str(&json).unwrap();
+    assert!(chunked.iter().eq(vec.iter()));
+}
+
 static DROP_COUNT: AtomicUsize = AtomicUsize::new(0);
 
 #[derive(Debug)]